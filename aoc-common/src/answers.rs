@@ -0,0 +1,279 @@
+//! Registry of the accepted answers for the inputs of a day.
+//!
+//! Each day keeps an `answers.txt` file next to its inputs, with a line per input file holding the
+//! file name and the answers of both parts, separated by whitespace. A `-` marks an answer that is
+//! not known yet, and lines starting with `#` are comments.
+
+use std::{
+    fmt,
+    fmt::{
+        Display,
+        Formatter,
+    },
+    fs,
+    io,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use snafu::prelude::*;
+
+use crate::{
+    input::Part,
+    solution,
+    solution::Solution,
+};
+
+pub const REGISTRY: &str = "answers.txt";
+
+#[non_exhaustive]
+#[derive(Debug, Snafu)]
+#[snafu(module(error), context(suffix(false)))]
+pub enum Error {
+    #[snafu(display("unable to read '{}'", path.display()))]
+    Read { source: io::Error, path: PathBuf },
+    #[snafu(display("invalid registry entry at line {line}: '{entry}'"))]
+    InvalidEntry { line: usize, entry: String },
+    #[snafu(display("unable to solve '{input}'"))]
+    Solve {
+        source: Box<dyn std::error::Error>,
+        input: String,
+    },
+    #[snafu(display("answers differ from the registry:\n{}", Mismatches(mismatches)))]
+    Mismatched { mismatches: Vec<Mismatch> },
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Expected {
+    pub input: String,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Mismatch {
+    pub input: String,
+    pub part: Part,
+    pub expected: String,
+    pub actual: String,
+}
+
+struct Mismatches<'m>(&'m [Mismatch]);
+
+impl Display for Mismatches<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for Mismatch { input, part, expected, actual } in self.0 {
+            writeln!(
+                f,
+                "  {input} part {part}: expected {expected}, got {actual}"
+            )?;
+        }
+        Ok(())
+    }
+}
+
+pub fn parse_registry(registry: &str) -> Result<Vec<Expected>, Error> {
+    let known = |answer: &str| (answer != "-").then(|| answer.to_owned());
+
+    registry
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(index, line)| {
+            let fields: Vec<_> = line.split_whitespace().collect();
+            let [input, part_1, part_2] = fields[..] else {
+                return error::InvalidEntry { line: index + 1, entry: line }.fail();
+            };
+
+            Ok(Expected {
+                input: input.to_owned(),
+                part_1: known(part_1),
+                part_2: known(part_2),
+            })
+        })
+        .collect()
+}
+
+/// Solves every input recorded in the registry of the directory, comparing against the answers.
+///
+/// Returns how many answers were checked.
+pub fn check<S>(dir: impl AsRef<Path>) -> Result<usize, Error>
+where
+    S: Solution,
+{
+    let dir = dir.as_ref();
+    let read = |path: PathBuf| fs::read_to_string(&path).context(error::Read { path });
+
+    let registry = parse_registry(&read(dir.join(REGISTRY))?)?;
+
+    let mut checked = 0;
+    let mut mismatches = Vec::new();
+    for Expected { input, part_1, part_2 } in registry {
+        let content = read(dir.join(&input))?;
+        let report = solution::solve::<S>(&content, None)
+            .map_err(|error| Box::new(error) as Box<dyn std::error::Error>)
+            .context(error::Solve { input: &input })?;
+
+        for answer in report.answers {
+            let expected = match answer.part {
+                Part::One => &part_1,
+                Part::Two => &part_2,
+            };
+            let Some(expected) = expected else { continue };
+
+            checked += 1;
+            if *expected != answer.answer {
+                mismatches.push(Mismatch {
+                    input: input.clone(),
+                    part: answer.part,
+                    expected: expected.clone(),
+                    actual: answer.answer,
+                });
+            }
+        }
+    }
+
+    ensure!(mismatches.is_empty(), error::Mismatched { mismatches });
+    Ok(checked)
+}
+
+/// Test helper that panics with a readable report unless every recorded answer is reproduced.
+pub fn verify<S>(dir: impl AsRef<Path>)
+where
+    S: Solution,
+{
+    match check::<S>(dir) {
+        Ok(0) => panic!("no answers are recorded for day {}", S::DAY),
+        Ok(_) => {}
+        Err(error) => panic!("day {}: {error}", S::DAY),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        convert::Infallible,
+        env,
+    };
+
+    use super::*;
+
+    struct Length;
+
+    impl Solution for Length {
+        type Answer1 = usize;
+        type Answer2 = usize;
+        type Error = Infallible;
+        type Parsed<'i> = &'i str;
+
+        const DAY: u8 = 0;
+        const INPUT: &'static str = "";
+
+        fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+            Ok(input.trim_end())
+        }
+
+        fn part_1(input: &Self::Parsed<'_>) -> Result<Self::Answer1, Self::Error> {
+            Ok(input.len())
+        }
+
+        fn part_2(input: &Self::Parsed<'_>) -> Result<Self::Answer2, Self::Error> {
+            Ok(input.lines().count())
+        }
+    }
+
+    fn registry_dir(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-answers-{name}-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for (file, content) in files {
+            fs::write(dir.join(file), content).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn parse_entries() {
+        const REGISTRY: &str =
+            "# input part_1 part_2\n\nexample.txt 24000 45000\ngiven.txt\t1  -\n";
+
+        let entries = parse_registry(REGISTRY).unwrap();
+
+        assert_eq!(
+            entries,
+            vec![
+                Expected {
+                    input: "example.txt".into(),
+                    part_1: Some("24000".into()),
+                    part_2: Some("45000".into()),
+                },
+                Expected {
+                    input: "given.txt".into(),
+                    part_1: Some("1".into()),
+                    part_2: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_invalid_entry() {
+        let error = parse_registry("given.txt 1 2\ngiven.txt 1\n").unwrap_err();
+
+        if let Error::InvalidEntry { line, entry } = error {
+            assert_eq!(line, 2);
+            assert_eq!(entry, "given.txt 1");
+        } else {
+            panic!("expected an invalid entry")
+        }
+    }
+
+    #[test]
+    fn check_matching() {
+        let dir = registry_dir(
+            "matching",
+            &[
+                (REGISTRY, "a.txt 5 2\nb.txt 3 -\n"),
+                ("a.txt", "ab\ncd\n"),
+                ("b.txt", "abc"),
+            ],
+        );
+
+        assert_eq!(check::<Length>(&dir).unwrap(), 3);
+    }
+
+    #[test]
+    fn check_mismatched() {
+        let dir = registry_dir(
+            "mismatched",
+            &[(REGISTRY, "a.txt 5 3\n"), ("a.txt", "ab\ncd\n")],
+        );
+
+        let error = check::<Length>(&dir).unwrap_err();
+
+        if let Error::Mismatched { mismatches } = error {
+            assert_eq!(
+                mismatches,
+                vec![Mismatch {
+                    input: "a.txt".into(),
+                    part: Part::Two,
+                    expected: "3".into(),
+                    actual: "2".into(),
+                }]
+            );
+        } else {
+            panic!("expected mismatched answers")
+        }
+    }
+
+    #[test]
+    fn check_missing_input() {
+        let dir = registry_dir("missing", &[(REGISTRY, "nope.txt 1 2\n")]);
+
+        let error = check::<Length>(&dir).unwrap_err();
+
+        assert!(matches!(error, Error::Read { .. }));
+    }
+}
@@ -0,0 +1,70 @@
+//! Shared harness for the criterion benchmarks of every day.
+//!
+//! Run a day's suite with `cargo bench -p day-N`. To compare a change against the current code, save
+//! a baseline first with `cargo bench -p day-N -- --save-baseline before`, then run the changed code
+//! with `cargo bench -p day-N -- --baseline before`.
+
+use criterion::{
+    BenchmarkId,
+    Criterion,
+    Throughput,
+};
+
+use crate::{
+    generate,
+    generate::Generator,
+    solution::Solution,
+};
+
+/// Seed of the generated inputs, fixed so that runs compare the same inputs.
+const SEED: u64 = 2022;
+
+/// Benchmarks the parsing and each part of the solution separately, for every named input.
+pub fn bench_solution<S>(c: &mut Criterion, inputs: &[(&str, &str)])
+where
+    S: Solution,
+{
+    let mut group = c.benchmark_group(format!("day-{}", S::DAY));
+
+    for &(name, input) in inputs {
+        group.throughput(Throughput::Bytes(input.len() as u64));
+
+        group.bench_with_input(BenchmarkId::new("parse", name), input, |b, input| {
+            b.iter(|| S::parse(input))
+        });
+
+        let Ok(parsed) = S::parse(input) else {
+            panic!("the {name} input of day {} does not parse", S::DAY)
+        };
+        group.bench_with_input(BenchmarkId::new("part_1", name), &parsed, |b, parsed| {
+            b.iter(|| S::part_1(parsed))
+        });
+        group.bench_with_input(BenchmarkId::new("part_2", name), &parsed, |b, parsed| {
+            b.iter(|| S::part_2(parsed))
+        });
+    }
+
+    group.finish();
+}
+
+/// Benchmarks the solution over generated inputs of every size, to measure how it scales.
+pub fn bench_generated<G>(c: &mut Criterion, sizes: &[usize])
+where
+    G: Generator,
+{
+    let inputs: Vec<_> = sizes
+        .iter()
+        .map(|&size| {
+            (
+                format!("generated-{size}"),
+                generate::seeded::<G>(SEED, size),
+            )
+        })
+        .collect();
+    let inputs: Vec<_> = inputs
+        .iter()
+        .map(|(name, input)| (name.as_str(), input.as_str()))
+        .collect();
+
+    bench_solution::<G>(c, &inputs);
+}
@@ -0,0 +1,89 @@
+//! Seeded generation of arbitrarily large, valid puzzle inputs.
+//!
+//! The same seed and size always produce the same input, so stress tests and benchmarks are
+//! reproducible across runs and machines.
+
+pub use rand;
+use rand::{
+    Rng,
+    SeedableRng,
+};
+use rand_chacha::ChaCha8Rng;
+
+use crate::solution::Solution;
+
+pub trait Generator: Solution {
+    /// Generates a valid input made of `size` records, whose unit depends on the puzzle.
+    fn generate<R>(rng: &mut R, size: usize) -> String
+    where
+        R: Rng;
+}
+
+/// Generates the input of the given size from a reproducible random number generator.
+pub fn seeded<G>(seed: u64, size: usize) -> String
+where
+    G: Generator,
+{
+    G::generate(&mut ChaCha8Rng::seed_from_u64(seed), size)
+}
+
+#[cfg(test)]
+mod test {
+    use std::convert::Infallible;
+
+    use yare::parameterized;
+
+    use super::*;
+
+    struct Numbers;
+
+    impl Solution for Numbers {
+        type Answer1 = usize;
+        type Answer2 = usize;
+        type Error = Infallible;
+        type Parsed<'i> = &'i str;
+
+        const DAY: u8 = 0;
+        const INPUT: &'static str = "";
+
+        fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+            Ok(input)
+        }
+
+        fn part_1(input: &Self::Parsed<'_>) -> Result<Self::Answer1, Self::Error> {
+            Ok(input.lines().count())
+        }
+
+        fn part_2(input: &Self::Parsed<'_>) -> Result<Self::Answer2, Self::Error> {
+            Ok(input.len())
+        }
+    }
+
+    impl Generator for Numbers {
+        fn generate<R>(rng: &mut R, size: usize) -> String
+        where
+            R: Rng,
+        {
+            (0..size)
+                .map(|_| format!("{}\n", rng.gen::<u32>()))
+                .collect()
+        }
+    }
+
+    #[parameterized(
+        empty   = { 0 },
+        single  = { 1 },
+        many    = { 100 },
+    )]
+    fn seeded_is_reproducible(size: usize) {
+        let input = seeded::<Numbers>(42, size);
+
+        assert_eq!(input, seeded::<Numbers>(42, size));
+        assert_eq!(input.lines().count(), size);
+    }
+
+    #[test]
+    fn seeds_differ() {
+        assert_ne!(seeded::<Numbers>(1, 10), seeded::<Numbers>(2, 10));
+    }
+}
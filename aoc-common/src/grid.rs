@@ -0,0 +1,106 @@
+use ndarray::Array2;
+use snafu::prelude::*;
+
+#[non_exhaustive]
+#[derive(Debug, Snafu)]
+#[snafu(module(error), context(suffix(false)))]
+pub enum Error {
+    #[snafu(display("line {line} has {found} cells instead of {expected}"))]
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    #[snafu(display("invalid digit '{found}' at line {line}, column {column}"))]
+    InvalidDigit {
+        line: usize,
+        column: usize,
+        found: char,
+    },
+}
+
+/// Loads a grid of single digit cells, one row per non-empty line.
+pub fn digit_grid(input: &str) -> Result<Array2<u8>, Error> {
+    let mut cols = None;
+    let mut cells = Vec::with_capacity(input.len());
+
+    for (index, line) in input.lines().map(str::trim_end).enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+
+        let expected = *cols.get_or_insert(line.len());
+        ensure!(
+            line.len() == expected,
+            error::Ragged {
+                line: index + 1,
+                expected,
+                found: line.len()
+            }
+        );
+
+        for (column, c) in line.chars().enumerate() {
+            let digit = c.to_digit(10).context(error::InvalidDigit {
+                line: index + 1,
+                column: column + 1,
+                found: c,
+            })?;
+            cells.push(digit as u8);
+        }
+    }
+
+    let cols = cols.unwrap_or_default();
+    let rows = cells.len().checked_div(cols).unwrap_or_default();
+    Ok(Array2::from_shape_vec((rows, cols), cells)
+        .expect("rows are checked to have the same length"))
+}
+
+#[cfg(test)]
+mod test {
+    use ndarray::array;
+
+    use super::*;
+
+    #[test]
+    fn load_square() {
+        let grid = digit_grid("123\n456\n789\n").unwrap();
+
+        assert_eq!(grid, array![[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    }
+
+    #[test]
+    fn load_rectangle_crlf() {
+        let grid = digit_grid("12\r\n34\r\n56").unwrap();
+
+        assert_eq!(grid, array![[1, 2], [3, 4], [5, 6]]);
+    }
+
+    #[test]
+    fn load_empty() {
+        let grid = digit_grid("").unwrap();
+
+        assert_eq!(grid.dim(), (0, 0));
+    }
+
+    #[test]
+    fn load_ragged() {
+        let error = digit_grid("123\n45\n").unwrap_err();
+
+        if let Error::Ragged { line, expected, found } = error {
+            assert_eq!((line, expected, found), (2, 3, 2));
+        } else {
+            panic!("expected a ragged grid")
+        }
+    }
+
+    #[test]
+    fn load_invalid_digit() {
+        let error = digit_grid("123\n4x6\n").unwrap_err();
+
+        if let Error::InvalidDigit { line, column, found } = error {
+            assert_eq!((line, column, found), (2, 2, 'x'));
+        } else {
+            panic!("expected an invalid digit")
+        }
+    }
+}
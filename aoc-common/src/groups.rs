@@ -0,0 +1,61 @@
+/// Iterator over the groups of lines separated by blank lines.
+///
+/// Consecutive blank lines count as a single separator, and lines with only whitespace are blank.
+/// Each group keeps its inner line endings but not the trailing one.
+#[derive(Clone, Debug)]
+pub struct Groups<'i> {
+    rest: &'i str,
+}
+
+pub fn groups(input: &str) -> Groups<'_> {
+    Groups { rest: input }
+}
+
+impl<'i> Iterator for Groups<'i> {
+    type Item = &'i str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut start = None;
+        let mut end = 0;
+
+        for line in self.rest.split_inclusive('\n') {
+            let blank = line.trim().is_empty();
+            match start {
+                None if blank => {}
+                None => start = Some(end),
+                Some(_) if blank => break,
+                Some(_) => {}
+            }
+            end += line.len();
+        }
+
+        let group = start.map(|start| &self.rest[start..end]);
+        self.rest = &self.rest[end..];
+        group.map(|group| group.trim_end_matches(['\r', '\n']))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use yare::parameterized;
+
+    use super::*;
+
+    #[parameterized(
+        empty           = { "",                      &[] },
+        blank           = { "\n  \n\n",              &[] },
+        single          = { "1\n2",                  &["1\n2"] },
+        trailing_line   = { "1\n2\n",                &["1\n2"] },
+        two             = { "1\n2\n\n3",             &["1\n2", "3"] },
+        many_blanks     = { "1\n\n\n\n3\n",          &["1", "3"] },
+        leading_blanks  = { "\n\n1\n\n3",            &["1", "3"] },
+        whitespace_line = { "1\n   \n3",             &["1", "3"] },
+        crlf            = { "1\r\n2\r\n\r\n3\r\n",   &["1\r\n2", "3"] },
+        indented        = { "    [D]\n[N] [C]\n\nm", &["    [D]\n[N] [C]", "m"] },
+    )]
+    fn split_groups(input: &str, expected: &[&str]) {
+        let actual: Vec<_> = groups(input).collect();
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,208 @@
+use std::{
+    borrow::Cow,
+    fmt,
+    fmt::{
+        Display,
+        Formatter,
+    },
+    fs,
+    fs::File,
+    io,
+    io::{
+        BufRead,
+        BufReader,
+        Read,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use clap::{
+    Parser,
+    ValueEnum,
+};
+use snafu::prelude::*;
+
+use crate::output::Format;
+
+#[non_exhaustive]
+#[derive(Debug, Snafu)]
+#[snafu(module(error), context(suffix(false)))]
+pub enum Error {
+    #[snafu(display("unable to read the input from stdin"))]
+    ReadStdin { source: io::Error },
+    #[snafu(display("unable to read the input file '{}'", path.display()))]
+    ReadFile { source: io::Error, path: PathBuf },
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug, ValueEnum)]
+pub enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+}
+
+impl Part {
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// Command line selection of the puzzle input, the parts to solve and how to print the answers.
+#[derive(Clone, Debug, Default, Parser)]
+pub struct Input {
+    /// Input file to solve, or `-` to read it from stdin. Defaults to the bundled input.
+    pub path: Option<PathBuf>,
+
+    /// Solve only the given part.
+    #[arg(short, long)]
+    pub part: Option<Part>,
+
+    /// Print the answers as prose, or as machine-readable records.
+    #[arg(short, long, value_enum, default_value_t)]
+    pub format: Format,
+}
+
+impl Input {
+    pub fn from_env() -> Self {
+        Self::parse()
+    }
+
+    pub fn runs(&self, part: Part) -> bool {
+        self.part.is_none_or(|selected| selected == part)
+    }
+
+    pub fn read(&self, bundled: &'static str) -> Result<Cow<'static, str>, Error> {
+        match self.path.as_deref() {
+            None => Ok(Cow::Borrowed(bundled)),
+            Some(path) if path == Path::new("-") => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .context(error::ReadStdin)?;
+                Ok(Cow::Owned(input))
+            }
+            Some(path) => fs::read_to_string(path)
+                .map(Cow::Owned)
+                .context(error::ReadFile { path }),
+        }
+    }
+
+    /// Opens the input to read it line by line, without loading all of it in memory.
+    pub fn reader(&self, bundled: &'static str) -> Result<Box<dyn BufRead>, Error> {
+        match self.path.as_deref() {
+            None => Ok(Box::new(bundled.as_bytes())),
+            Some(path) if path == Path::new("-") => Ok(Box::new(io::stdin().lock())),
+            Some(path) => File::open(path)
+                .map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+                .context(error::ReadFile { path }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use yare::parameterized;
+
+    use super::*;
+
+    #[parameterized(
+        nothing     = { &[],                     None,                          None },
+        path        = { &["given.txt"],          Some("given.txt"),             None },
+        stdin       = { &["-"],                  Some("-"),                     None },
+        part        = { &["--part", "1"],        None,                          Some(Part::One) },
+        short_part  = { &["-p", "2"],            None,                          Some(Part::Two) },
+        both        = { &["-p", "2", "in.txt"],  Some("in.txt"),                Some(Part::Two) },
+    )]
+    fn parse_args(args: &[&str], path: Option<&str>, part: Option<Part>) {
+        let input = Input::try_parse_from(["day"].iter().chain(args)).unwrap();
+
+        assert_eq!(input.path, path.map(PathBuf::from));
+        assert_eq!(input.part, part);
+    }
+
+    #[parameterized(
+        invalid_part    = { &["--part", "3"] },
+        missing_part    = { &["--part"] },
+        two_paths       = { &["a.txt", "b.txt"] },
+    )]
+    fn parse_args_fails(args: &[&str]) {
+        let result = Input::try_parse_from(["day"].iter().chain(args));
+
+        assert!(result.is_err());
+    }
+
+    #[parameterized(
+        all         = { None,            Part::One, true },
+        selected    = { Some(Part::Two), Part::Two, true },
+        other       = { Some(Part::Two), Part::One, false },
+    )]
+    fn runs(part: Option<Part>, check: Part, expected: bool) {
+        let input = Input { part, ..Input::default() };
+
+        assert_eq!(input.runs(check), expected);
+    }
+
+    #[test]
+    fn read_bundled() {
+        let input = Input::default();
+
+        let result = input.read("bundled").unwrap();
+
+        assert!(matches!(result, Cow::Borrowed("bundled")));
+    }
+
+    #[test]
+    fn read_missing_file() {
+        let input = Input {
+            path: Some(PathBuf::from("does/not/exist.txt")),
+            ..Input::default()
+        };
+
+        let error = input.read("bundled").unwrap_err();
+
+        if let Error::ReadFile { path, .. } = error {
+            assert_eq!(path, Path::new("does/not/exist.txt"));
+        } else {
+            panic!("expected a file read error")
+        }
+    }
+
+    #[test]
+    fn reader_bundled() {
+        let input = Input::default();
+
+        let lines: Vec<_> = input
+            .reader("a\nb\n")
+            .unwrap()
+            .lines()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(lines, ["a", "b"]);
+    }
+
+    #[test]
+    fn reader_missing_file() {
+        let input = Input {
+            path: Some(PathBuf::from("does/not/exist.txt")),
+            ..Input::default()
+        };
+
+        let result = input.reader("bundled");
+
+        assert!(matches!(result, Err(Error::ReadFile { .. })));
+    }
+}
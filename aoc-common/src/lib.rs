@@ -0,0 +1,10 @@
+pub mod answers;
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod generate;
+pub mod grid;
+pub mod groups;
+pub mod input;
+pub mod output;
+pub mod parser;
+pub mod solution;
@@ -0,0 +1,202 @@
+use std::{
+    error::Error,
+    io,
+    io::Write,
+};
+
+use clap::ValueEnum;
+use serde::{
+    Serialize,
+    Serializer,
+};
+
+use crate::{
+    input::Part,
+    solution,
+    solution::{
+        Report,
+        Solution,
+    },
+};
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum Format {
+    /// Human readable sentences or tables.
+    #[default]
+    Text,
+    /// One JSON object per line, for each answer.
+    Json,
+    /// Tab separated values with a header row.
+    Tsv,
+}
+
+/// Machine-readable record of a single answer.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    #[serde(serialize_with = "part_number")]
+    pub part: Part,
+    pub answer: String,
+    pub duration_ns: u64,
+    pub input_hash: String,
+}
+
+fn part_number<S: Serializer>(part: &Part, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u8(part.number())
+}
+
+/// Stable 64-bit FNV-1a hash of the input, in hexadecimal, to tell inputs apart across runs.
+pub fn input_hash(input: &str) -> String {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let hash = input.bytes().fold(OFFSET, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    });
+    format!("{hash:016x}")
+}
+
+pub fn records(report: &Report, input: &str) -> Vec<Record> {
+    let input_hash = input_hash(input);
+    report
+        .answers
+        .iter()
+        .map(|answer| Record {
+            day: report.day,
+            part: answer.part,
+            answer: answer.answer.clone(),
+            duration_ns: u64::try_from(answer.elapsed.as_nanos()).unwrap_or(u64::MAX),
+            input_hash: input_hash.clone(),
+        })
+        .collect()
+}
+
+pub fn write_records(format: Format, records: &[Record], mut out: impl Write) -> io::Result<()> {
+    match format {
+        Format::Text => {
+            for Record { day, part, answer, duration_ns, .. } in records {
+                writeln!(out, "day {day} part {part}: {answer} ({duration_ns}ns)")?;
+            }
+        }
+        Format::Json => {
+            for record in records {
+                serde_json::to_writer(&mut out, record)?;
+                writeln!(out)?;
+            }
+        }
+        Format::Tsv => {
+            writeln!(out, "day\tpart\tanswer\tduration_ns\tinput_hash")?;
+            for Record {
+                day,
+                part,
+                answer,
+                duration_ns,
+                input_hash,
+            } in records
+            {
+                writeln!(out, "{day}\t{part}\t{answer}\t{duration_ns}\t{input_hash}")?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Solves the input and prints the records of the selected parts to stdout.
+pub fn print<S>(format: Format, input: &str, part: Option<Part>) -> Result<(), Box<dyn Error>>
+where
+    S: Solution,
+{
+    print_with::<S, _>(format, input, part, S::parse)
+}
+
+/// Like [`print`], parsing the input with `parse`, see [`solution::solve_with`].
+pub fn print_with<S, P>(
+    format: Format,
+    input: &str,
+    part: Option<Part>,
+    parse: P,
+) -> Result<(), Box<dyn Error>>
+where
+    S: Solution,
+    P: FnOnce(&str) -> Result<S::Parsed<'_>, S::Error>,
+{
+    let report = solution::solve_with::<S, _>(input, part, parse)?;
+    write_records(format, &records(&report, input), io::stdout().lock())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use yare::parameterized;
+
+    use super::*;
+    use crate::solution::Answer;
+
+    fn report() -> Report {
+        Report {
+            day: 4,
+            parse: Duration::from_micros(30),
+            answers: vec![
+                Answer {
+                    part: Part::One,
+                    answer: "532".into(),
+                    elapsed: Duration::from_nanos(1200),
+                },
+                Answer {
+                    part: Part::Two,
+                    answer: "854".into(),
+                    elapsed: Duration::from_nanos(900),
+                },
+            ],
+        }
+    }
+
+    #[parameterized(
+        empty   = { "",  "cbf29ce484222325" },
+        a       = { "a", "af63dc4c8601ec8c" },
+        foobar  = { "foobar", "85944171f73967e8" },
+    )]
+    fn hash_input(input: &str, expected: &str) {
+        assert_eq!(input_hash(input), expected);
+    }
+
+    #[test]
+    fn build_records() {
+        let records = records(&report(), "foobar");
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].part, Part::Two);
+        assert_eq!(records[1].answer, "854");
+        assert_eq!(records[1].duration_ns, 900);
+        assert_eq!(records[1].input_hash, "85944171f73967e8");
+    }
+
+    #[parameterized(
+        text = {
+            Format::Text,
+            "day 4 part 1: 532 (1200ns)\nday 4 part 2: 854 (900ns)\n",
+        },
+        json = {
+            Format::Json,
+            concat!(
+                r#"{"day":4,"part":1,"answer":"532","duration_ns":1200,"input_hash":"85944171f73967e8"}"#, "\n",
+                r#"{"day":4,"part":2,"answer":"854","duration_ns":900,"input_hash":"85944171f73967e8"}"#, "\n",
+            ),
+        },
+        tsv = {
+            Format::Tsv,
+            "day\tpart\tanswer\tduration_ns\tinput_hash\n\
+             4\t1\t532\t1200\t85944171f73967e8\n\
+             4\t2\t854\t900\t85944171f73967e8\n",
+        },
+    )]
+    fn write_formats(format: Format, expected: &str) {
+        let mut out = Vec::new();
+
+        write_records(format, &records(&report(), "foobar"), &mut out).unwrap();
+
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+    }
+}
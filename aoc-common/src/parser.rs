@@ -0,0 +1,113 @@
+pub use nom::{
+    self,
+    character::complete::*,
+    combinator::*,
+    multi::*,
+    sequence::*,
+};
+use snafu::prelude::*;
+
+pub type Result<'i, T> = nom::IResult<&'i str, T>;
+
+#[non_exhaustive]
+#[derive(Debug, Snafu)]
+#[snafu(module(error), context(suffix(false)))]
+pub enum Error {
+    #[snafu(display("invalid input at line {line} ({kind:?}): '{found}'"))]
+    Invalid {
+        line: usize,
+        found: String,
+        kind: nom::error::ErrorKind,
+    },
+    #[snafu(display("unexpected input at line {line}: '{found}'"))]
+    Trailing { line: usize, found: String },
+    #[snafu(display("the input ended unexpectedly"))]
+    Incomplete,
+}
+
+/// Parses one item per line, ignoring any whitespace around the whole input.
+pub fn lines<'i, O>(
+    parser: impl FnMut(&'i str) -> Result<'i, O>,
+) -> impl FnMut(&'i str) -> Result<'i, Vec<O>> {
+    delimited(
+        multispace0,
+        separated_list0(line_ending, parser),
+        multispace0,
+    )
+}
+
+/// Runs the parser over the whole input, failing if anything is left unparsed.
+pub fn parse_all<'i, O>(
+    input: &'i str,
+    mut parser: impl FnMut(&'i str) -> Result<'i, O>,
+) -> std::result::Result<O, Error> {
+    match parser(input) {
+        Ok(("", output)) => Ok(output),
+        Ok((rest, _)) => error::Trailing {
+            line: line_of(input, rest),
+            found: first_line(rest),
+        }
+        .fail(),
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => error::Invalid {
+            line: line_of(input, err.input),
+            found: first_line(err.input),
+            kind: err.code,
+        }
+        .fail(),
+        Err(nom::Err::Incomplete(_)) => error::Incomplete.fail(),
+    }
+}
+
+fn line_of(input: &str, rest: &str) -> usize {
+    let offset = input.len() - rest.len();
+    input[..offset].matches('\n').count() + 1
+}
+
+fn first_line(rest: &str) -> String {
+    rest.lines().next().unwrap_or_default().to_owned()
+}
+
+#[cfg(test)]
+mod test {
+    use yare::parameterized;
+
+    use super::*;
+
+    #[parameterized(
+        empty       = { "",               vec![] },
+        single      = { "1",              vec![1] },
+        many        = { "1\n2\n3",        vec![1, 2, 3] },
+        crlf        = { "1\r\n2\r\n",     vec![1, 2] },
+        surrounded  = { "\n\n 1\n2\n\n",  vec![1, 2] },
+    )]
+    fn parse_lines(input: &str, expected: Vec<u32>) {
+        let result = parse_all(input, lines(u32));
+
+        assert_eq!(result.unwrap(), expected);
+    }
+
+    #[test]
+    fn parse_all_trailing() {
+        let error = parse_all("1\n2\nuwu\n3", lines(u32)).unwrap_err();
+
+        if let Error::Trailing { line, found } = error {
+            assert_eq!(line, 3);
+            assert_eq!(found, "uwu");
+        } else {
+            panic!("expected trailing input")
+        }
+    }
+
+    #[test]
+    fn parse_all_invalid() {
+        let error = parse_all("1\nx-2", separated_pair(u32, char('\n'), u32)).unwrap_err();
+
+        if let Error::Invalid { line, found, kind } = error {
+            assert_eq!(line, 2);
+            assert_eq!(found, "x-2");
+            assert_eq!(kind, nom::error::ErrorKind::Digit);
+        } else {
+            panic!("expected invalid input")
+        }
+    }
+}
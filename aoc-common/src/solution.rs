@@ -0,0 +1,181 @@
+use std::{
+    error,
+    fmt::Display,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use crate::input::Part;
+
+/// A day's puzzle solver, split into the parsing step and the two parts.
+pub trait Solution {
+    const DAY: u8;
+    const INPUT: &'static str;
+
+    type Parsed<'i>;
+    type Error: error::Error + 'static;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error>;
+
+    fn part_1(parsed: &Self::Parsed<'_>) -> Result<Self::Answer1, Self::Error>;
+
+    fn part_2(parsed: &Self::Parsed<'_>) -> Result<Self::Answer2, Self::Error>;
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Answer {
+    pub part: Part,
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Report {
+    pub day: u8,
+    pub parse: Duration,
+    pub answers: Vec<Answer>,
+}
+
+/// Solves the selected parts of the given input, timing each step separately.
+pub fn solve<S>(input: &str, part: Option<Part>) -> Result<Report, S::Error>
+where
+    S: Solution,
+{
+    solve_with::<S, _>(input, part, S::parse)
+}
+
+/// Like [`solve`], parsing the input with `parse` instead of [`Solution::parse`], for inputs read
+/// with options the solution alone does not know.
+pub fn solve_with<S, P>(input: &str, part: Option<Part>, parse: P) -> Result<Report, S::Error>
+where
+    S: Solution,
+    P: FnOnce(&str) -> Result<S::Parsed<'_>, S::Error>,
+{
+    let runs = |selected| part.is_none_or(|part| part == selected);
+
+    let (parsed, parse) = timed(|| parse(input));
+    let parsed = parsed?;
+
+    let mut answers = Vec::with_capacity(2);
+    if runs(Part::One) {
+        let (answer, elapsed) = timed(|| S::part_1(&parsed));
+        let answer = answer?.to_string();
+        answers.push(Answer { part: Part::One, answer, elapsed });
+    }
+    if runs(Part::Two) {
+        let (answer, elapsed) = timed(|| S::part_2(&parsed));
+        let answer = answer?.to_string();
+        answers.push(Answer { part: Part::Two, answer, elapsed });
+    }
+
+    Ok(Report { day: S::DAY, parse, answers })
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let value = f();
+    (value, start.elapsed())
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        convert::Infallible,
+        num::ParseIntError,
+    };
+
+    use yare::parameterized;
+
+    use super::*;
+
+    struct Doubling;
+
+    impl Solution for Doubling {
+        type Answer1 = u32;
+        type Answer2 = u32;
+        type Error = ParseIntError;
+        type Parsed<'i> = u32;
+
+        const DAY: u8 = 0;
+        const INPUT: &'static str = "21";
+
+        fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+            input.parse()
+        }
+
+        fn part_1(parsed: &Self::Parsed<'_>) -> Result<Self::Answer1, Self::Error> {
+            Ok(parsed * 2)
+        }
+
+        fn part_2(parsed: &Self::Parsed<'_>) -> Result<Self::Answer2, Self::Error> {
+            Ok(parsed * 4)
+        }
+    }
+
+    struct Borrowing;
+
+    impl Solution for Borrowing {
+        type Answer1 = usize;
+        type Answer2 = String;
+        type Error = Infallible;
+        type Parsed<'i> = &'i str;
+
+        const DAY: u8 = 0;
+        const INPUT: &'static str = "";
+
+        fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+            Ok(input)
+        }
+
+        fn part_1(parsed: &Self::Parsed<'_>) -> Result<Self::Answer1, Self::Error> {
+            Ok(parsed.len())
+        }
+
+        fn part_2(parsed: &Self::Parsed<'_>) -> Result<Self::Answer2, Self::Error> {
+            Ok(parsed.to_uppercase())
+        }
+    }
+
+    #[parameterized(
+        all = { None,            &[(Part::One, "42"), (Part::Two, "84")] },
+        one = { Some(Part::One), &[(Part::One, "42")] },
+        two = { Some(Part::Two), &[(Part::Two, "84")] },
+    )]
+    fn solve_parts(part: Option<Part>, expected: &[(Part, &str)]) {
+        let report = solve::<Doubling>(Doubling::INPUT, part).unwrap();
+
+        let answers: Vec<_> = report
+            .answers
+            .iter()
+            .map(|answer| (answer.part, answer.answer.as_str()))
+            .collect();
+        assert_eq!(answers, expected);
+    }
+
+    #[test]
+    fn solve_borrowed() {
+        let report = solve::<Borrowing>("uwu", Some(Part::Two)).unwrap();
+
+        assert_eq!(report.answers[0].answer, "UWU");
+    }
+
+    #[test]
+    fn solve_with_parser() {
+        let report =
+            solve_with::<Doubling, _>("uwu", Some(Part::One), |input| Ok(input.len() as u32))
+                .unwrap();
+
+        assert_eq!(report.answers[0].answer, "6");
+    }
+
+    #[test]
+    fn solve_fails_parse() {
+        let result = solve::<Doubling>("uwu", None);
+
+        assert!(result.is_err());
+    }
+}
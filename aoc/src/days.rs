@@ -0,0 +1,98 @@
+use std::error::Error;
+
+use aoc_common::{
+    generate,
+    generate::Generator,
+    input::Part,
+    solution,
+    solution::Report,
+};
+use day_1::Day1;
+use day_2::Day2;
+use day_3::Day3;
+use day_4::Day4;
+use day_5::Day5;
+use day_6::Day6;
+use day_7::Day7;
+use day_8::Day8;
+
+pub type Solver = fn(&str, Option<Part>) -> Result<Report, Box<dyn Error>>;
+
+/// Generates an input of the given size from a seed.
+pub type Seeded = fn(u64, usize) -> String;
+
+#[derive(Copy, Clone)]
+pub struct Day {
+    pub day: u8,
+    pub input: &'static str,
+    pub solve: Solver,
+    pub generate: Seeded,
+}
+
+pub const DAYS: [Day; 8] = [
+    day::<Day1>(),
+    day::<Day2>(),
+    day::<Day3>(),
+    day::<Day4>(),
+    day::<Day5>(),
+    day::<Day6>(),
+    day::<Day7>(),
+    day::<Day8>(),
+];
+
+const fn day<S: Generator>() -> Day {
+    Day {
+        day: S::DAY,
+        input: S::INPUT,
+        solve: solve::<S>,
+        generate: generate::seeded::<S>,
+    }
+}
+
+fn solve<S: Generator>(input: &str, part: Option<Part>) -> Result<Report, Box<dyn Error>> {
+    Ok(solution::solve::<S>(input, part)?)
+}
+
+pub fn find(day: u8) -> Option<Day> {
+    DAYS.iter()
+        .copied()
+        .find(|registered| registered.day == day)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn registered_in_order() {
+        let days: Vec<_> = DAYS.iter().map(|day| day.day).collect();
+
+        assert_eq!(days, (1..=8).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn find_missing() {
+        assert!(find(0).is_none());
+        assert!(find(25).is_none());
+    }
+
+    #[test]
+    fn bundled_inputs_solve() {
+        for day in DAYS {
+            let report = (day.solve)(day.input, None).unwrap();
+
+            assert_eq!(report.day, day.day);
+            assert_eq!(report.answers.len(), 2);
+        }
+    }
+
+    #[test]
+    fn generated_inputs_solve() {
+        for day in DAYS {
+            let input = (day.generate)(day.day.into(), 50);
+
+            assert_eq!(input, (day.generate)(day.day.into(), 50));
+            assert!((day.solve)(&input, None).is_ok());
+        }
+    }
+}
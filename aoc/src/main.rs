@@ -0,0 +1,196 @@
+use std::{
+    borrow::Cow,
+    error::Error,
+    io,
+    str::FromStr,
+};
+
+use aoc_common::{
+    input::Input,
+    output,
+    output::Format,
+};
+use clap::{
+    Parser,
+    Subcommand,
+};
+
+use crate::table::Table;
+
+mod days;
+mod table;
+
+#[derive(Debug, Parser)]
+#[command(name = "aoc", about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve the puzzles of one or all the days.
+    Run {
+        /// Day to solve, or `all` to solve every day with its bundled input.
+        day: Selection,
+
+        #[command(flatten)]
+        input: Input,
+    },
+    /// Print a random but valid input of a day, reproducible from its seed.
+    Generate {
+        /// Day to generate an input for.
+        #[arg(value_parser = parse_day)]
+        day: u8,
+
+        /// Number of records in the input, whose unit depends on the day.
+        #[arg(short, long, default_value_t = 1_000)]
+        size: usize,
+
+        /// Seed of the random number generator.
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum Selection {
+    All,
+    Day(u8),
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.eq_ignore_ascii_case("all") {
+            return Ok(Self::All);
+        }
+
+        parse_day(value).map(Self::Day)
+    }
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    let day = value
+        .parse()
+        .map_err(|_| format!("'{value}' is not a day"))?;
+    days::find(day)
+        .map(|_| day)
+        .ok_or_else(|| format!("day {day} is not solved yet"))
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    match Cli::parse().command {
+        Command::Run { day, input } => run(day, input),
+        Command::Generate { day, size, seed } => {
+            let day = days::find(day).expect("validated by the argument parser");
+            print!("{}", (day.generate)(seed, size));
+            Ok(())
+        }
+    }
+}
+
+fn run(day: Selection, input: Input) -> Result<(), Box<dyn Error>> {
+    let solved = match day {
+        Selection::All => {
+            if input.path.is_some() {
+                return Err("an input file can only be given when running a single day".into());
+            }
+            days::DAYS
+                .iter()
+                .map(|day| {
+                    Ok((
+                        (day.solve)(day.input, input.part)?,
+                        Cow::Borrowed(day.input),
+                    ))
+                })
+                .collect::<Result<Vec<_>, Box<dyn Error>>>()?
+        }
+        Selection::Day(day) => {
+            let day = days::find(day).expect("validated by the argument parser");
+            let content = input.read(day.input)?;
+            vec![((day.solve)(&content, input.part)?, content)]
+        }
+    };
+
+    if input.format == Format::Text {
+        let reports: Vec<_> = solved.into_iter().map(|(report, _)| report).collect();
+        print!("{}", Table::new(&reports));
+    } else {
+        let records: Vec<_> = solved
+            .iter()
+            .flat_map(|(report, content)| output::records(report, content))
+            .collect();
+        output::write_records(input.format, &records, io::stdout().lock())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use yare::parameterized;
+
+    use super::*;
+
+    #[parameterized(
+        all     = { "all", Selection::All },
+        caps    = { "ALL", Selection::All },
+        first   = { "1",   Selection::Day(1) },
+        last    = { "8",   Selection::Day(8) },
+    )]
+    fn parse_selection(value: &str, expected: Selection) {
+        assert_eq!(Selection::from_str(value), Ok(expected));
+    }
+
+    #[parameterized(
+        zero        = { "0" },
+        unsolved    = { "25" },
+        word        = { "uwu" },
+        negative    = { "-1" },
+    )]
+    fn parse_selection_fails(value: &str) {
+        assert!(Selection::from_str(value).is_err());
+    }
+
+    #[test]
+    fn parse_run() {
+        let cli = Cli::try_parse_from(["aoc", "run", "3", "--part", "2"]).unwrap();
+
+        let Command::Run { day, input } = cli.command else {
+            panic!("expected the run command")
+        };
+        assert_eq!(day, Selection::Day(3));
+        assert_eq!(input.part, Some(aoc_common::input::Part::Two));
+        assert_eq!(input.format, Format::Text);
+    }
+
+    #[test]
+    fn parse_run_format() {
+        let cli = Cli::try_parse_from(["aoc", "run", "all", "--format", "tsv"]).unwrap();
+
+        let Command::Run { day, input } = cli.command else {
+            panic!("expected the run command")
+        };
+        assert_eq!(day, Selection::All);
+        assert_eq!(input.format, Format::Tsv);
+    }
+
+    #[test]
+    fn parse_generate() {
+        let cli = Cli::try_parse_from(["aoc", "generate", "5", "--size", "10"]).unwrap();
+
+        let Command::Generate { day, size, seed } = cli.command else {
+            panic!("expected the generate command")
+        };
+        assert_eq!((day, size, seed), (5, 10, 0));
+    }
+
+    #[parameterized(
+        all         = { "all" },
+        unsolved    = { "25" },
+    )]
+    fn parse_generate_fails(day: &str) {
+        assert!(Cli::try_parse_from(["aoc", "generate", day]).is_err());
+    }
+}
@@ -0,0 +1,107 @@
+use std::{
+    fmt,
+    fmt::{
+        Display,
+        Formatter,
+    },
+    iter,
+    time::Duration,
+};
+
+use aoc_common::solution::Report;
+
+const HEADER: [&str; 4] = ["Day", "Part", "Answer", "Time"];
+
+/// Plain text table with a row for each day's parsing and for each solved part.
+pub struct Table(Vec<[String; 4]>);
+
+impl Table {
+    pub fn new(reports: &[Report]) -> Self {
+        let rows = reports
+            .iter()
+            .flat_map(|report| {
+                let day = report.day.to_string();
+                let parse = [
+                    day.clone(),
+                    "parse".into(),
+                    String::new(),
+                    time(report.parse),
+                ];
+                let parts = report.answers.iter().map(move |answer| {
+                    [
+                        day.clone(),
+                        answer.part.to_string(),
+                        answer.answer.clone(),
+                        time(answer.elapsed),
+                    ]
+                });
+                iter::once(parse).chain(parts)
+            })
+            .collect();
+
+        Self(rows)
+    }
+}
+
+fn time(duration: Duration) -> String {
+    format!("{duration:.1?}")
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let widths: [usize; 4] = std::array::from_fn(|column| {
+            self.0
+                .iter()
+                .map(|row| row[column].chars().count())
+                .chain([HEADER[column].len()])
+                .max()
+                .unwrap_or_default()
+        });
+
+        let [day, part, answer, time] = widths;
+        let [h_day, h_part, h_answer, h_time] = HEADER;
+        writeln!(
+            f,
+            "{h_day:>day$}  {h_part:<part$}  {h_answer:<answer$}  {h_time:>time$}"
+        )?;
+        for [r_day, r_part, r_answer, r_time] in &self.0 {
+            writeln!(
+                f,
+                "{r_day:>day$}  {r_part:<part$}  {r_answer:<answer$}  {r_time:>time$}"
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{
+        input::Part,
+        solution::Answer,
+    };
+
+    use super::*;
+
+    #[test]
+    fn aligns_columns() {
+        let report = Report {
+            day: 1,
+            parse: Duration::from_micros(12),
+            answers: vec![Answer {
+                part: Part::One,
+                answer: "69836".into(),
+                elapsed: Duration::from_nanos(1500),
+            }],
+        };
+
+        let table = Table::new(&[report]).to_string();
+
+        assert_eq!(
+            table,
+            "Day  Part   Answer    Time\n  \
+               1  parse          12.0µs\n  \
+               1  1      69836    1.5µs\n"
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use aoc_common::{
+    bench::{
+        bench_generated,
+        bench_solution,
+    },
+    generate,
+    solution::Solution,
+};
+use criterion::{
+    criterion_group,
+    criterion_main,
+    BenchmarkId,
+    Criterion,
+    Throughput,
+};
+use day_1::{
+    parallel,
+    top_elves,
+    Config,
+    Day1,
+    TOP_ELVES,
+};
+
+fn synthetic() -> String {
+    vec![Day1::INPUT.trim_end(); 200].join("\n\n")
+}
+
+fn solution(c: &mut Criterion) {
+    let synthetic = synthetic();
+    bench_solution::<Day1>(c, &[("given", Day1::INPUT), ("synthetic", &synthetic)]);
+}
+
+fn generated(c: &mut Criterion) {
+    bench_generated::<Day1>(c, &[100, 1_000, 10_000]);
+}
+
+/// Compares the sequential and parallel sums over generated inputs large enough to be split.
+fn parallel(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-1-parallel");
+    let config = Config::default();
+
+    for size in [10_000, 100_000, 1_000_000] {
+        let input = generate::seeded::<Day1>(2022, size);
+        group.throughput(Throughput::Bytes(input.len() as u64));
+
+        group.bench_with_input(BenchmarkId::new("sequential", size), &input, |b, input| {
+            b.iter(|| top_elves(input, TOP_ELVES, &config))
+        });
+        group.bench_with_input(BenchmarkId::new("parallel", size), &input, |b, input| {
+            b.iter(|| parallel::top_elves(input, TOP_ELVES, &config))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, solution, generated, parallel);
+criterion_main!(benches);
@@ -0,0 +1,49 @@
+use std::fmt::Write;
+
+use aoc_common::generate::{
+    rand::Rng,
+    Generator,
+};
+
+use crate::Day1;
+
+impl Generator for Day1 {
+    /// Generates the food list of `size` elves, or three elves when fewer are asked for, since the
+    /// second part needs them.
+    fn generate<R>(rng: &mut R, size: usize) -> String
+    where
+        R: Rng,
+    {
+        let mut input = String::new();
+        for elf in 0..size.max(3) {
+            if elf > 0 {
+                input.push('\n');
+            }
+            for _ in 0..rng.gen_range(1..=15) {
+                writeln!(input, "{}", rng.gen_range(1_000..=70_000)).unwrap();
+            }
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{
+        generate::seeded,
+        solution::solve,
+    };
+
+    use super::*;
+    use crate::sum_batched_lined;
+
+    #[test]
+    fn generated_inputs_solve() {
+        for (seed, size) in [(0, 0), (1, 3), (2, 100), (3, 10_000)] {
+            let input = seeded::<Day1>(seed, size);
+
+            assert_eq!(sum_batched_lined(&input).count(), size.max(3));
+            assert!(solve::<Day1>(&input, None).is_ok());
+        }
+    }
+}
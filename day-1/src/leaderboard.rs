@@ -0,0 +1,89 @@
+use std::{
+    fmt,
+    fmt::{
+        Display,
+        Formatter,
+    },
+};
+
+use crate::Elf;
+
+const HEADER: [&str; 5] = ["Rank", "Elf", "Line", "Items", "Calories"];
+
+/// Ranking of the elves carrying the most calories, with where to find them in the input.
+pub struct Leaderboard(Vec<Elf>);
+
+impl Leaderboard {
+    /// Ranks the elves by the calories they carry, breaking ties by their order in the input.
+    pub fn new(mut elves: Vec<Elf>) -> Self {
+        elves.sort_unstable_by(|a, b| b.cmp(a));
+        Self(elves)
+    }
+}
+
+impl Display for Leaderboard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let rows: Vec<[String; 5]> = self
+            .0
+            .iter()
+            .enumerate()
+            .map(|(rank, elf)| {
+                [
+                    rank + 1,
+                    elf.index + 1,
+                    elf.line,
+                    elf.items,
+                    elf.calories as usize,
+                ]
+                .map(|cell| cell.to_string())
+            })
+            .collect();
+
+        let widths: [usize; 5] = std::array::from_fn(|column| {
+            rows.iter()
+                .map(|row| row[column].len())
+                .chain([HEADER[column].len()])
+                .max()
+                .unwrap_or_default()
+        });
+
+        for row in [HEADER.map(String::from)].iter().chain(&rows) {
+            let cells: Vec<_> = row
+                .iter()
+                .zip(widths)
+                .map(|(cell, width)| format!("{cell:>width$}"))
+                .collect();
+            writeln!(f, "{}", cells.join("  "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        elves,
+        Config,
+    };
+
+    #[test]
+    fn display_example() {
+        const INPUT: &str = include_str!("inputs/example.txt");
+
+        let elves = elves(INPUT, &Config::default()).collect::<Result<_, _>>();
+        let leaderboard = Leaderboard::new(elves.unwrap());
+
+        assert_eq!(
+            leaderboard.to_string(),
+            "\
+Rank  Elf  Line  Items  Calories
+   1    4    10      3     24000
+   2    3     7      2     11000
+   3    5    14      1     10000
+   4    1     1      3      6000
+   5    2     5      1      4000
+"
+        );
+    }
+}
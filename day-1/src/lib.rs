@@ -0,0 +1,622 @@
+use std::{
+    cmp::{
+        Ordering,
+        Reverse,
+    },
+    io,
+    io::BufRead,
+    iter,
+    num::IntErrorKind,
+};
+
+use aoc_common::solution::Solution;
+use snafu::prelude::*;
+
+use crate::top::TopN;
+
+pub mod generate;
+pub mod leaderboard;
+pub mod parallel;
+pub mod stats;
+pub mod top;
+
+/// Number of elves whose calories the second part sums.
+pub const TOP_ELVES: usize = 3;
+
+#[non_exhaustive]
+#[derive(Debug, Snafu)]
+#[snafu(module(error), context(suffix(false)))]
+pub enum Error {
+    #[snafu(display("unable to read the calorie list"))]
+    Read { source: io::Error },
+    #[snafu(display("line {line} is not a number of calories: '{content}'"))]
+    InvalidItem { line: usize, content: String },
+    #[snafu(display("the calories of elf {} overflow at line {line}", elf + 1))]
+    Overflow { elf: usize, line: usize },
+    #[snafu(display("needed {needed} elves, but only {found} carry food"))]
+    NotEnoughElves { needed: usize, found: usize },
+}
+
+/// How to handle the lines of the calorie list that are neither blank nor a number.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum Parsing {
+    /// Fail on the first such line.
+    #[default]
+    Strict,
+    /// Skip such lines, keeping the elf they belong to whole. A group of such lines alone is no elf.
+    Lenient,
+}
+
+/// Lines that end the inventory of an elf. Lines are compared without their surrounding whitespace,
+/// so CRLF line endings and trailing spaces make no difference.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum Separator {
+    /// Blank lines, as in the puzzle.
+    #[default]
+    Blank,
+    /// Lines made of the marker, such as `---`. Blank lines are then ignored.
+    Marker(String),
+}
+
+impl Separator {
+    pub(crate) fn matches(&self, line: &str) -> bool {
+        match self {
+            Separator::Blank => line.is_empty(),
+            Separator::Marker(marker) => line == marker.trim(),
+        }
+    }
+}
+
+/// How to read the calorie list.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Config {
+    pub parsing: Parsing,
+    pub separator: Separator,
+}
+
+impl Config {
+    pub fn new(parsing: Parsing, separator: Separator) -> Self {
+        Self { parsing, separator }
+    }
+}
+
+/// The food carried by an elf, and where it is listed in the input.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Elf {
+    /// Position of the elf in the input, from 0.
+    pub index: usize,
+    /// Line of the first item of the elf, from 1.
+    pub line: usize,
+    pub items: usize,
+    pub calories: u32,
+}
+
+/// Orders elves by the calories they carry, then earlier elves first among equals.
+impl Ord for Elf {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.calories, Reverse(self.index)).cmp(&(other.calories, Reverse(other.index)))
+    }
+}
+
+impl PartialOrd for Elf {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Answer1 = u32;
+    type Answer2 = u32;
+    type Error = Error;
+    type Parsed<'i> = TopN<Elf>;
+
+    const DAY: u8 = 1;
+    const INPUT: &'static str = include_str!("inputs/given.txt");
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+        Self::parse_with(input, &Config::default())
+    }
+
+    fn part_1(top: &Self::Parsed<'_>) -> Result<Self::Answer1, Self::Error> {
+        sum_top(top, 1)
+    }
+
+    fn part_2(top: &Self::Parsed<'_>) -> Result<Self::Answer2, Self::Error> {
+        sum_top(top, TOP_ELVES)
+    }
+}
+
+impl Day1 {
+    /// Like [`Solution::parse`], reading the calorie list as configured.
+    pub fn parse_with(input: &str, config: &Config) -> Result<TopN<Elf>, Error> {
+        collect_top(elves(input, config), TOP_ELVES)
+    }
+}
+
+fn sum_top(top: &TopN<Elf>, needed: usize) -> Result<u32, Error> {
+    let elves = top.largest();
+    ensure!(
+        elves.len() >= needed,
+        error::NotEnoughElves { needed, found: elves.len() }
+    );
+
+    Ok(elves[..needed].iter().map(|elf| elf.calories).sum())
+}
+
+/// Finds the `n` elves carrying the most calories, most first.
+pub fn top_elves(input: &str, n: usize, config: &Config) -> Result<Vec<Elf>, Error> {
+    Ok(collect_top(elves(input, config), n)?.largest())
+}
+
+/// Streams the calorie list, keeping only the `n` elves carrying the most.
+pub fn top_from_reader(
+    reader: impl BufRead,
+    n: usize,
+    config: &Config,
+) -> Result<TopN<Elf>, Error> {
+    collect_top(ReadElves::new(reader, config), n)
+}
+
+/// Keeps the `n` elves carrying the most, stopping at the first error.
+fn collect_top(
+    elves: impl IntoIterator<Item = Result<Elf, Error>>,
+    n: usize,
+) -> Result<TopN<Elf>, Error> {
+    let mut top = TopN::new(n);
+    for elf in elves {
+        top.push(elf?);
+    }
+    Ok(top)
+}
+
+/// Groups the lines of the calorie list into elves.
+#[derive(Default)]
+struct Batcher {
+    config: Config,
+    index: usize,
+    current: Option<Elf>,
+}
+
+impl Batcher {
+    fn new(config: &Config) -> Self {
+        Self {
+            config: config.clone(),
+            ..Self::default()
+        }
+    }
+
+    /// Adds the line to the current elf, returning it instead when the line is a separator.
+    fn feed(&mut self, number: usize, line: &str) -> Result<Option<Elf>, Error> {
+        let line = line.trim();
+        if self.config.separator.matches(line) {
+            return Ok(self.finish());
+        }
+        if line.is_empty() {
+            return Ok(None);
+        }
+
+        let index = self.index;
+        let overflow = error::Overflow { elf: index, line: number };
+
+        match line.parse::<u32>() {
+            Ok(calories) => {
+                let elf = self.current.get_or_insert(Elf {
+                    index,
+                    line: number,
+                    items: 0,
+                    calories: 0,
+                });
+                elf.items += 1;
+                elf.calories = elf.calories.checked_add(calories).context(overflow)?;
+            }
+            Err(error) if *error.kind() == IntErrorKind::PosOverflow => return overflow.fail(),
+            Err(_) if self.config.parsing == Parsing::Lenient => {}
+            Err(_) => {
+                return error::InvalidItem { line: number, content: line }.fail();
+            }
+        }
+        Ok(None)
+    }
+
+    fn finish(&mut self) -> Option<Elf> {
+        let elf = self.current.take()?;
+        self.index += 1;
+        Some(elf)
+    }
+}
+
+/// Lists the food carried by each elf, in the order they appear in the input.
+pub fn elves<'i>(input: &'i str, config: &Config) -> impl Iterator<Item = Result<Elf, Error>> + 'i {
+    let mut batcher = Batcher::new(config);
+    let mut lines = input.lines().zip(1..);
+
+    iter::from_fn(move || {
+        for (line, number) in lines.by_ref() {
+            if let Some(elf) = batcher.feed(number, line).transpose() {
+                return Some(elf);
+            }
+        }
+        batcher.finish().map(Ok)
+    })
+}
+
+/// Sums the calories carried by each elf, in the order they appear in the input, any line that is
+/// not a number ending the current elf.
+///
+/// # Panics
+/// If the calories of an elf overflow, see [`elves`] to handle it as an error instead.
+pub fn sum_batched_lined(input: &str) -> impl Iterator<Item = u32> + '_ {
+    let mut items = input
+        .lines()
+        .map(|line| line.trim().parse::<u32>().ok())
+        .peekable();
+
+    iter::from_fn(move || {
+        while items.next_if(Option::is_none).is_some() {}
+        items.peek()?;
+
+        let elf = iter::from_fn(|| items.next_if(Option::is_some).flatten());
+        Some(elf.fold(0_u32, |sum, calories| {
+            sum.checked_add(calories)
+                .expect("the calories of an elf overflow")
+        }))
+    })
+}
+
+/// Sums the calories carried by each elf, reading the calorie list as configured.
+pub fn sum_batched<'i>(
+    input: &'i str,
+    config: &Config,
+) -> impl Iterator<Item = Result<u32, Error>> + 'i {
+    elves(input, config).map(|elf| elf.map(|elf| elf.calories))
+}
+
+/// Lists the food carried by each elf while reading the list line by line.
+pub struct ReadElves<R> {
+    reader: R,
+    line: String,
+    number: usize,
+    batcher: Batcher,
+}
+
+impl<R> ReadElves<R>
+where
+    R: BufRead,
+{
+    pub fn new(reader: R, config: &Config) -> Self {
+        Self {
+            reader,
+            line: String::new(),
+            number: 0,
+            batcher: Batcher::new(config),
+        }
+    }
+}
+
+impl<R> Iterator for ReadElves<R>
+where
+    R: BufRead,
+{
+    type Item = Result<Elf, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => return self.batcher.finish().map(Ok),
+                Ok(_) => self.number += 1,
+                Err(source) => return Some(Err(Error::Read { source })),
+            }
+
+            if let Some(elf) = self.batcher.feed(self.number, &self.line).transpose() {
+                return Some(elf);
+            }
+        }
+    }
+}
+
+/// Sums the `n` largest values of the slice, reordering it in the process.
+///
+/// # Panics
+/// If the slice has less than `n` values.
+pub fn sum_n_largest(slice: &mut [u32], n: usize) -> u32 {
+    let start = slice.len() - n;
+    slice.select_nth_unstable(start);
+    slice[start..].iter().sum()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{
+        input::Part,
+        solution,
+    };
+    use itertools::Itertools;
+    use yare::parameterized;
+
+    use super::*;
+
+    #[test]
+    fn iter_example() {
+        const INPUT: &str = include_str!("inputs/example.txt");
+
+        let result = sum_batched_lined(INPUT);
+
+        assert_eq!(result.collect_vec(), vec![6000, 4000, 11000, 24000, 10000])
+    }
+
+    #[test]
+    fn iter_no_empty_line_at_end() {
+        const INPUT: &str = "1000";
+
+        let result = sum_batched_lined(INPUT);
+
+        assert_eq!(result.collect_vec(), vec![1000])
+    }
+
+    #[test]
+    fn iter_many_empty_lines() {
+        const INPUT: &str = "1000\n\n\n2000\n3000\n\n";
+
+        let result = sum_batched_lined(INPUT);
+
+        assert_eq!(result.collect_vec(), vec![1000, 5000])
+    }
+
+    #[parameterized(
+        word        = { "1\nx\n2",         vec![1, 2] },
+        repeated    = { "1\nx\n\nx\n2",    vec![1, 2] },
+        leading     = { "x\n1\n2\nx",      vec![3] },
+        none        = { "x\n\n",           vec![] },
+    )]
+    fn iter_other_lines_end_elves(input: &str, expected: Vec<u32>) {
+        let result = sum_batched_lined(input);
+
+        assert_eq!(result.collect_vec(), expected)
+    }
+
+    #[test]
+    fn read_elves_like_lines() {
+        const INPUTS: [&str; 4] = [
+            include_str!("inputs/example.txt"),
+            "1000",
+            "\n\n1000\n\n\n2000\n3000\n\n",
+            "1000\r\nx\r\n \r\nx\r\n",
+        ];
+
+        let lenient = Config::new(Parsing::Lenient, Separator::Blank);
+        for input in INPUTS {
+            let read: Vec<_> = ReadElves::new(input.as_bytes(), &lenient)
+                .collect::<Result<_, _>>()
+                .unwrap();
+            let elves: Vec<_> = elves(input, &lenient).collect::<Result<_, _>>().unwrap();
+
+            assert_eq!(read, elves);
+        }
+    }
+
+    #[test]
+    fn top_from_reader_works() {
+        const INPUT: &str = include_str!("inputs/example.txt");
+
+        let top = top_from_reader(INPUT.as_bytes(), TOP_ELVES, &Config::default()).unwrap();
+
+        let calories: Vec<_> = top.largest().iter().map(|elf| elf.calories).collect();
+        assert_eq!(calories, [24000, 11000, 10000]);
+    }
+
+    #[test]
+    fn top_elves_example() {
+        const INPUT: &str = include_str!("inputs/example.txt");
+
+        let top = top_elves(INPUT, 2, &Config::default()).unwrap();
+
+        assert_eq!(
+            top,
+            [
+                Elf {
+                    index: 3,
+                    line: 10,
+                    items: 3,
+                    calories: 24000
+                },
+                Elf {
+                    index: 2,
+                    line: 7,
+                    items: 2,
+                    calories: 11000
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn top_elves_ties_keep_input_order() {
+        let top = top_elves("5\n\n7\n\n5\n\n5", 3, &Config::default()).unwrap();
+
+        let indices: Vec<_> = top.iter().map(|elf| elf.index).collect();
+        assert_eq!(indices, [1, 0, 2]);
+    }
+
+    #[test]
+    fn elves_skip_invalid_items() {
+        let elves: Vec<_> = elves(
+            "\n\nx\n1000\nx\n2000\n\n\nx\n\n3",
+            &Config::new(Parsing::Lenient, Separator::Blank),
+        )
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+        // The group of invalid items alone is no elf.
+        assert_eq!(
+            elves,
+            [
+                Elf {
+                    index: 0,
+                    line: 4,
+                    items: 2,
+                    calories: 3000
+                },
+                Elf {
+                    index: 1,
+                    line: 11,
+                    items: 1,
+                    calories: 3
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn not_enough_elves() {
+        let top = Day1::parse("1000\n\n2000").unwrap();
+
+        let error = Day1::part_2(&top).unwrap_err();
+
+        assert!(matches!(
+            error,
+            Error::NotEnoughElves { needed: 3, found: 2 }
+        ));
+    }
+
+    #[parameterized(
+        typo        = { "1000\n12a4\n\n2000",   2, "12a4" },
+        negative    = { "1000\n\n-5",            3, "-5" },
+        word        = { "\n\nuwu\n",             3, "uwu" },
+        trimmed     = { "1000\r\n 7 kg \r\n",     2, "7 kg" },
+    )]
+    fn strict_invalid_item(input: &str, expected_line: usize, expected_content: &str) {
+        let error = elves(input, &Config::default())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+
+        if let Error::InvalidItem { line, content } = error {
+            assert_eq!(line, expected_line);
+            assert_eq!(content, expected_content);
+        } else {
+            panic!("expected an invalid item")
+        }
+    }
+
+    #[parameterized(
+        sum     = { "1\n\n4294967295\n1",  1, 4 },
+        item    = { "4294967296",            0, 1 },
+    )]
+    fn overflow(input: &str, expected_elf: usize, expected_line: usize) {
+        for parsing in [Parsing::Strict, Parsing::Lenient] {
+            let error = elves(input, &Config::new(parsing, Separator::Blank))
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap_err();
+
+            if let Error::Overflow { elf, line } = error {
+                assert_eq!((elf, line), (expected_elf, expected_line));
+            } else {
+                panic!("expected an overflow")
+            }
+        }
+    }
+
+    #[test]
+    fn read_strict_invalid_item() {
+        let result = top_from_reader("1000\n12a4\n".as_bytes(), TOP_ELVES, &Config::default());
+
+        assert!(matches!(result, Err(Error::InvalidItem { line: 2, .. })));
+    }
+
+    #[test]
+    fn parse_is_strict() {
+        let result = Day1::parse("1000\n12a4\n");
+
+        assert!(matches!(result, Err(Error::InvalidItem { line: 2, .. })));
+    }
+
+    #[test]
+    fn parse_with_lenient() {
+        let config = Config::new(Parsing::Lenient, Separator::Blank);
+
+        let report = solution::solve_with::<Day1, _>("1\nx\n2\n\n4", Some(Part::One), |input| {
+            Day1::parse_with(input, &config)
+        })
+        .unwrap();
+
+        assert_eq!(report.answers[0].answer, "4");
+        assert!(solution::solve::<Day1>("1\nx\n2\n\n4", None).is_err());
+    }
+
+    #[test]
+    fn parse_with_marker() {
+        let config = Config::new(Parsing::Strict, Separator::Marker("---".into()));
+
+        let top = Day1::parse_with("1000\n2000\n---\n5000\n---\n300\n", &config).unwrap();
+
+        assert_eq!(Day1::part_1(&top).unwrap(), 5000);
+        assert_eq!(Day1::part_2(&top).unwrap(), 8300);
+        assert!(Day1::parse("1000\n2000\n---\n5000\n").is_err());
+    }
+
+    #[parameterized(
+        blank           = { "1\n2\n\n3\n",                  Separator::Blank },
+        crlf            = { "1\r\n2\r\n\r\n3\r\n",            Separator::Blank },
+        trailing_spaces = { "1  \n2\t\n   \n3 \n",          Separator::Blank },
+        marker          = { "1\n2\n---\n3\n",               Separator::Marker("---".into()) },
+        marker_crlf     = { "1\r\n\r\n2\r\n--- \r\n3\r\n",   Separator::Marker("---".into()) },
+        marker_repeated = { "---\n1\n2\n---\n---\n3",      Separator::Marker("---".into()) },
+    )]
+    fn separators(input: &str, separator: Separator) {
+        let sums: Vec<_> = sum_batched(input, &Config::new(Parsing::Strict, separator))
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(sums, [3, 3]);
+    }
+
+    #[parameterized(
+        invalid     = { "1\n\nx\n2",          Parsing::Strict,  3 },
+        overflow    = { "1\n\n4294967296",    Parsing::Lenient, 3 },
+    )]
+    fn sum_batched_fails(input: &str, parsing: Parsing, expected_line: usize) {
+        let mut sums = sum_batched(input, &Config::new(parsing, Separator::Blank));
+
+        assert_eq!(sums.next().unwrap().unwrap(), 1);
+        assert!(matches!(
+            sums.next(),
+            Some(Err(Error::InvalidItem { line, .. } | Error::Overflow { line, .. }))
+                if line == expected_line
+        ));
+    }
+
+    #[test]
+    fn marker_keeps_blank_lines_in_groups() {
+        let config = Config::new(Parsing::Strict, Separator::Marker("---".into()));
+
+        let elves: Vec<_> = elves("1\n\n2\n---\n3", &config)
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        let items: Vec<_> = elves.iter().map(|elf| elf.items).collect();
+        assert_eq!(items, [2, 1]);
+    }
+
+    #[test]
+    fn marker_is_invalid_with_blank_separator() {
+        let error = elves("1\n---\n2", &Config::default())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+
+        assert!(matches!(error, Error::InvalidItem { line: 2, .. }));
+    }
+
+    #[test]
+    fn flush_count() {
+        const INPUT: &str = include_str!("inputs/flush_count.txt");
+
+        let sums: Vec<_> = sum_batched_lined(INPUT).collect();
+        let top = Day1::parse(INPUT).unwrap();
+
+        assert_eq!(sums, [6000, 4000, 11000]);
+        assert_eq!(Day1::part_1(&top).unwrap(), 11000);
+        assert_eq!(Day1::part_2(&top).unwrap(), 21000);
+    }
+}
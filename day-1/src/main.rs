@@ -1,49 +1,161 @@
-use itertools::Itertools;
+use std::error::Error;
 
-const INPUT: &str = include_str!("inputs/given.txt");
+use aoc_common::{
+    input::{
+        Input,
+        Part,
+    },
+    output::{
+        self,
+        Format,
+    },
+    solution::Solution,
+};
+use clap::Parser;
+use day_1::{
+    elves,
+    leaderboard::Leaderboard,
+    parallel,
+    stats::{
+        self,
+        Collector,
+    },
+    top::TopN,
+    Config,
+    Day1,
+    Parsing,
+    ReadElves,
+    Separator,
+    TOP_ELVES,
+};
 
-fn main() {
-    let mut sums = sum_batched_lined(INPUT).collect_vec();
+#[derive(Debug, Parser)]
+struct Args {
+    #[command(flatten)]
+    input: Input,
 
-    let result = sum_n_largest(&mut sums, 1);
-    println!("The amount of calories carried by one elf is {result}.");
+    /// Also rank the given number of elves carrying the most calories.
+    #[arg(short, long, value_name = "ELVES")]
+    leaderboard: Option<usize>,
 
-    let result = sum_n_largest(&mut sums, 3);
-    println!("The amount of calories carried by three elfs is {result}.");
-}
+    /// Also report statistics over the inventories of every elf. With `--format json`, they follow
+    /// the answers as a record tagged `"record": "statistics"`.
+    #[arg(short, long)]
+    stats: bool,
 
-fn sum_batched_lined(input: &str) -> impl Iterator<Item = u32> + '_ {
-    input
-        .lines()
-        .map(|line: &str| line.parse::<u32>().ok())
-        .batching(|iter| iter.while_some().sum1())
-}
+    /// Skip the lines that are not numbers instead of failing on them.
+    #[arg(long)]
+    lenient: bool,
+
+    /// Line ending the inventory of an elf, such as `---`, instead of a blank line.
+    #[arg(long, value_name = "MARKER")]
+    separator: Option<String>,
 
-fn sum_n_largest(slice: &mut [u32], n: usize) -> u32 {
-    let start = slice.len() - n;
-    slice.select_nth_unstable(start);
-    slice[start..].iter().sum()
+    /// Read the whole list first, then sum the elves on every core.
+    #[arg(long)]
+    parallel: bool,
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
+fn main() -> Result<(), Box<dyn Error>> {
+    let Args {
+        input: args,
+        leaderboard,
+        stats,
+        lenient,
+        separator,
+        parallel,
+    } = Args::parse();
+    let parsing = if lenient {
+        Parsing::Lenient
+    } else {
+        Parsing::Strict
+    };
+    let separator = separator.map_or(Separator::Blank, Separator::Marker);
+    let config = Config::new(parsing, separator);
 
-    #[test]
-    fn iter_example() {
-        const INPUT: &str = include_str!("inputs/example.txt");
+    if args.format != Format::Text {
+        if stats && args.format != Format::Json {
+            return Err("statistics are only printed as text or JSON".into());
+        }
+        if leaderboard.is_some() {
+            return Err("the leaderboard is only printed as text".into());
+        }
 
-        let result = sum_batched_lined(INPUT);
+        let input = args.read(Day1::INPUT)?;
+        output::print_with::<Day1, _>(args.format, &input, args.part, |input| {
+            if parallel {
+                parallel::top_elves(input, TOP_ELVES, &config)
+            } else {
+                Day1::parse_with(input, &config)
+            }
+        })?;
+        if stats {
+            let mut collector = Collector::default();
+            for elf in elves(&input, &config) {
+                collector.push(&elf?);
+            }
+            if let Some(statistics) = collector.finish() {
+                let record = stats::Record { day: Day1::DAY, statistics };
+                println!("{}", serde_json::to_string(&record)?);
+            }
+        }
+        return Ok(());
+    }
 
-        assert_eq!(result.collect_vec(), vec![6000, 4000, 11000, 24000, 10000])
+    let n = leaderboard.map_or(TOP_ELVES, |elves| elves.max(TOP_ELVES));
+    let mut top = TopN::new(n);
+    let mut collector = Collector::default();
+    if parallel {
+        let input = args.read(Day1::INPUT)?;
+        top = parallel::top_elves(&input, n, &config)?;
+        if stats {
+            for elf in elves(&input, &config) {
+                collector.push(&elf?);
+            }
+        }
+    } else {
+        for elf in ReadElves::new(args.reader(Day1::INPUT)?, &config) {
+            let elf = elf?;
+            if stats {
+                collector.push(&elf);
+            }
+            top.push(elf);
+        }
     }
 
-    #[test]
-    fn iter_no_empty_line_at_end() {
-        const INPUT: &str = "1000";
+    if args.runs(Part::One) {
+        let result = Day1::part_1(&top)?;
+        println!("The amount of calories carried by one elf is {result}.");
+    }
 
-        let result = sum_batched_lined(INPUT);
+    if args.runs(Part::Two) {
+        let result = Day1::part_2(&top)?;
+        println!("The amount of calories carried by three elfs is {result}.");
+    }
 
-        assert_eq!(result.collect_vec(), vec![1000])
+    if let Some(elves) = leaderboard {
+        let ranked = top.largest().into_iter().take(elves).collect();
+        print!("\n{}", Leaderboard::new(ranked));
+    }
+
+    if stats {
+        match collector.finish() {
+            Some(statistics) => print!("\n{statistics}"),
+            None => println!("\nNo elf carries any food."),
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use clap::CommandFactory;
+
+    use super::*;
+
+    #[test]
+    fn arguments() {
+        Args::command().debug_assert();
     }
 }
@@ -0,0 +1,184 @@
+//! Sums the inventories on worker threads, for calorie lists too large to go through line by line.
+
+use rayon::prelude::*;
+
+use crate::{
+    elves,
+    top::TopN,
+    Config,
+    Elf,
+    Error,
+};
+
+/// Smallest chunk worth handing to a worker thread, in bytes.
+const MIN_CHUNK: usize = 64 * 1024;
+
+/// Splits the input into about `count` chunks, each ending right after a separator line so that no
+/// elf spans two chunks.
+pub fn chunks<'i>(input: &'i str, config: &Config, count: usize) -> Vec<&'i str> {
+    let target = input.len().div_ceil(count.max(1)).max(1);
+    let mut chunks = Vec::with_capacity(count);
+    let mut start = 0;
+
+    while start < input.len() {
+        let end = chunk_end(input, config, start + target);
+        chunks.push(&input[start..end]);
+        start = end;
+    }
+
+    chunks
+}
+
+/// Finds the end of the first separator line that starts at or after `from`.
+fn chunk_end(input: &str, config: &Config, from: usize) -> usize {
+    let before = from.saturating_sub(1);
+    let rest = input.as_bytes().get(before..).unwrap_or_default();
+    let Some(offset) = rest.iter().position(|&byte| byte == b'\n') else {
+        return input.len();
+    };
+
+    let mut end = before + offset + 1;
+    for line in input[end..].split_inclusive('\n') {
+        end += line.len();
+        if config.separator.matches(line.trim()) {
+            return end;
+        }
+    }
+    input.len()
+}
+
+/// The elves of one chunk, numbered from the start of the chunk.
+struct Partial {
+    top: TopN<Elf>,
+    elves: usize,
+    lines: usize,
+}
+
+impl Partial {
+    fn new(chunk: &str, n: usize, config: &Config) -> Result<Self, Error> {
+        let mut top = TopN::new(n);
+        let mut count = 0;
+        for elf in elves(chunk, config) {
+            top.push(elf?);
+            count += 1;
+        }
+
+        Ok(Self {
+            top,
+            elves: count,
+            lines: chunk.bytes().filter(|&byte| byte == b'\n').count(),
+        })
+    }
+}
+
+/// Finds the `n` elves carrying the most calories like [`crate::top_elves`], summing chunks of the
+/// input in parallel and merging their own top `n`.
+pub fn top_elves(input: &str, n: usize, config: &Config) -> Result<TopN<Elf>, Error> {
+    let count = rayon::current_num_threads().min(input.len() / MIN_CHUNK);
+    top_elves_in(input, n, config, count)
+}
+
+fn top_elves_in(input: &str, n: usize, config: &Config, count: usize) -> Result<TopN<Elf>, Error> {
+    let partials: Vec<_> = chunks(input, config, count)
+        .into_par_iter()
+        .map(|chunk| Partial::new(chunk, n, config))
+        .collect();
+
+    let mut top = TopN::new(n);
+    let (mut elves, mut lines) = (0, 0);
+    for partial in partials {
+        let partial = partial.map_err(|error| shift_error(error, elves, lines))?;
+        top.extend(partial.top.largest().into_iter().map(|elf| Elf {
+            index: elf.index + elves,
+            line: elf.line + lines,
+            ..elf
+        }));
+        elves += partial.elves;
+        lines += partial.lines;
+    }
+
+    Ok(top)
+}
+
+/// Moves the position an error reports from the start of its chunk to the start of the input.
+fn shift_error(error: Error, elves: usize, lines: usize) -> Error {
+    match error {
+        Error::InvalidItem { line, content } => Error::InvalidItem { line: line + lines, content },
+        Error::Overflow { elf, line } => Error::Overflow {
+            elf: elf + elves,
+            line: line + lines,
+        },
+        error => error,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use yare::parameterized;
+
+    use super::*;
+    use crate::{
+        Parsing,
+        Separator,
+    };
+
+    const INPUT: &str = include_str!("inputs/given.txt");
+
+    #[parameterized(
+        one     = { 1 },
+        two     = { 2 },
+        some    = { 7 },
+        many    = { 100 },
+        more    = { 100_000 },
+    )]
+    fn chunks_end_at_separators(count: usize) {
+        let config = Config::default();
+
+        let chunks = chunks(INPUT, &config, count);
+
+        assert_eq!(chunks.concat(), INPUT);
+        assert!(chunks.len() <= count);
+        for chunk in &chunks[..chunks.len() - 1] {
+            assert!(chunk.ends_with("\n\n"), "{chunk:?}");
+        }
+    }
+
+    #[test]
+    fn chunks_end_at_markers() {
+        let config = Config::new(Parsing::Strict, Separator::Marker("---".into()));
+
+        let chunks = chunks("1\n\n2\n---\n3\n---\n4", &config, 4);
+
+        assert_eq!(chunks, ["1\n\n2\n---\n", "3\n---\n4"]);
+    }
+
+    #[parameterized(
+        given   = { INPUT },
+        crlf    = { &INPUT.replace('\n', "\r\n") },
+        example = { include_str!("inputs/example.txt") },
+        empty   = { "" },
+    )]
+    fn matches_sequential(input: &str) {
+        let config = Config::default();
+        let sequential = crate::top_elves(input, 10, &config).unwrap();
+
+        for count in [1, 3, 16, 1_000] {
+            let top = top_elves_in(input, 10, &config, count).unwrap();
+
+            assert_eq!(top.largest(), sequential);
+        }
+    }
+
+    #[parameterized(
+        invalid     = { "1\n\n2\n\n3\nx\n" },
+        overflow    = { "1\n\n2\n\n4294967295\n1\n" },
+    )]
+    fn errors_match_sequential(input: &str) {
+        let config = Config::default();
+        let sequential = crate::top_elves(input, 1, &config).unwrap_err();
+
+        let parallel = top_elves_in(input, 1, &config, 3).unwrap_err();
+
+        assert_eq!(parallel.to_string(), sequential.to_string());
+    }
+}
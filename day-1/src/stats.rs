@@ -0,0 +1,378 @@
+use std::{
+    collections::BTreeMap,
+    fmt,
+    fmt::{
+        Display,
+        Formatter,
+    },
+};
+
+use serde::Serialize;
+
+use crate::Elf;
+
+/// Percentiles reported for the calories and the items of the elves.
+pub const PERCENTILES: [u8; 5] = [10, 25, 75, 90, 99];
+
+/// Number of bins of the histogram of calories.
+pub const BINS: u32 = 10;
+
+const BAR_WIDTH: usize = 40;
+
+/// Distribution of a quantity over every elf.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Summary {
+    pub min: u64,
+    pub max: u64,
+    pub mean: f64,
+    pub median: f64,
+    /// Nearest-rank percentiles, paired with their rank from 0 to 100.
+    pub percentiles: Vec<(u8, u64)>,
+}
+
+impl Summary {
+    /// Summarizes the values, or returns `None` if there are none.
+    pub fn new(values: Vec<u64>) -> Option<Self> {
+        let mut counts = BTreeMap::new();
+        for value in values {
+            *counts.entry(value).or_default() += 1;
+        }
+        Self::from_counts(&counts)
+    }
+
+    /// Summarizes the values from the number of times each occurs, or returns `None` if there are
+    /// none.
+    pub fn from_counts(counts: &BTreeMap<u64, usize>) -> Option<Self> {
+        let (&min, _) = counts.first_key_value()?;
+        let (&max, _) = counts.last_key_value()?;
+
+        let len: usize = counts.values().sum();
+        // The value at the position in sorted order, from 0.
+        let nth = |position: usize| {
+            counts
+                .iter()
+                .scan(0, |seen, (&value, &count)| {
+                    *seen += count;
+                    Some((*seen, value))
+                })
+                .find(|&(seen, _)| seen > position)
+                .map(|(_, value)| value)
+                .expect("the position is within the values")
+        };
+        let total: u64 = counts
+            .iter()
+            .map(|(&value, &count)| value * count as u64)
+            .sum();
+        let mean = total as f64 / len as f64;
+        let median = if len.is_multiple_of(2) {
+            (nth(len / 2 - 1) + nth(len / 2)) as f64 / 2.0
+        } else {
+            nth(len / 2) as f64
+        };
+        let percentiles = PERCENTILES
+            .iter()
+            .map(|&rank| {
+                let nearest = (usize::from(rank) * len).div_ceil(100).max(1);
+                (rank, nth(nearest - 1))
+            })
+            .collect();
+
+        Some(Self { min, max, mean, median, percentiles })
+    }
+}
+
+/// Number of elves carrying calories in each of the equally wide ranges.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Histogram {
+    pub start: u32,
+    pub width: u32,
+    pub counts: Vec<usize>,
+}
+
+impl Histogram {
+    pub fn new(calories: &[u32]) -> Option<Self> {
+        let start = *calories.iter().min()?;
+        let end = *calories.iter().max()?;
+        let width = ((end - start) / BINS + 1).max(1);
+
+        let mut counts = vec![0; BINS as usize];
+        for &value in calories {
+            counts[((value - start) / width) as usize] += 1;
+        }
+
+        Some(Self { start, width, counts })
+    }
+}
+
+/// Statistics over the inventories of every elf.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Statistics {
+    pub elves: usize,
+    pub calories: Summary,
+    pub items: Summary,
+    pub histogram: Histogram,
+}
+
+impl Statistics {
+    /// Computes the statistics of the elves, or returns `None` if there are none.
+    pub fn new(elves: &[Elf]) -> Option<Self> {
+        let mut collector = Collector::default();
+        for elf in elves {
+            collector.push(elf);
+        }
+        collector.finish()
+    }
+}
+
+/// Gathers the statistics one elf at a time, keeping only the calories of each elf rather than the
+/// elves themselves.
+#[derive(Clone, Debug, Default)]
+pub struct Collector {
+    calories: Vec<u32>,
+    items: BTreeMap<u64, usize>,
+}
+
+impl Collector {
+    pub fn push(&mut self, elf: &Elf) {
+        self.calories.push(elf.calories);
+        *self.items.entry(elf.items as u64).or_default() += 1;
+    }
+
+    /// The statistics of the elves pushed so far, or `None` if there are none.
+    pub fn finish(&self) -> Option<Statistics> {
+        Some(Statistics {
+            elves: self.calories.len(),
+            calories: Summary::new(self.calories.iter().copied().map(u64::from).collect())?,
+            items: Summary::from_counts(&self.items)?,
+            histogram: Histogram::new(&self.calories)?,
+        })
+    }
+}
+
+/// Machine-readable record of the statistics, tagged to tell it apart from the records of the
+/// answers.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "record", rename = "statistics")]
+pub struct Record {
+    pub day: u8,
+    #[serde(flatten)]
+    pub statistics: Statistics,
+}
+
+impl Display for Statistics {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let header = ["", "Min", "Max", "Mean", "Median"]
+            .into_iter()
+            .map(String::from)
+            .chain(PERCENTILES.map(|rank| format!("P{rank}")));
+        let row = |name: &str, summary: &Summary| {
+            [
+                name.to_owned(),
+                summary.min.to_string(),
+                summary.max.to_string(),
+                format!("{:.1}", summary.mean),
+                format!("{:.1}", summary.median),
+            ]
+            .into_iter()
+            .chain(
+                summary
+                    .percentiles
+                    .iter()
+                    .map(|(_, value)| value.to_string()),
+            )
+            .collect::<Vec<_>>()
+        };
+        let rows = [
+            header.collect(),
+            row("Calories", &self.calories),
+            row("Items", &self.items),
+        ];
+
+        writeln!(f, "Elves: {}", self.elves)?;
+        let widths: Vec<_> = (0..rows[0].len())
+            .map(|column| rows.iter().map(|row| row[column].len()).max().unwrap_or(0))
+            .collect();
+        for row in &rows {
+            let (name, values) = row.split_first().unwrap();
+            write!(f, "{name:<width$}", width = widths[0])?;
+            for (value, width) in values.iter().zip(&widths[1..]) {
+                write!(f, "  {value:>width$}")?;
+            }
+            writeln!(f)?;
+        }
+
+        let Histogram { start, width, counts } = &self.histogram;
+        let most = counts.iter().copied().max().unwrap_or(0).max(1);
+        let ranges: Vec<_> = (0..BINS)
+            .map(|bin| {
+                let low = u64::from(*start) + u64::from(bin) * u64::from(*width);
+                format!("{low}..{}", low + u64::from(*width))
+            })
+            .collect();
+        let range_width = ranges.iter().map(String::len).max().unwrap_or(0);
+        let count_width = most.to_string().len();
+
+        writeln!(f, "\nCalories histogram:")?;
+        for (range, &count) in ranges.iter().zip(counts) {
+            let bar = "#".repeat((count * BAR_WIDTH).div_ceil(most));
+            let line = format!("{range:>range_width$}  {count:>count_width$}  {bar}");
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use yare::parameterized;
+
+    use super::*;
+    use crate::{
+        elves,
+        Config,
+    };
+
+    #[parameterized(
+        single  = { vec![7],              7.0, 7.0,  vec![(10, 7), (25, 7), (75, 7), (90, 7), (99, 7)] },
+        odd     = { vec![3, 1, 2],        2.0, 2.0,  vec![(10, 1), (25, 1), (75, 3), (90, 3), (99, 3)] },
+        even    = { vec![4, 1, 3, 2],     2.5, 2.5,  vec![(10, 1), (25, 1), (75, 3), (90, 4), (99, 4)] },
+        skewed  = { vec![1, 1, 1, 1, 16], 4.0, 1.0,  vec![(10, 1), (25, 1), (75, 1), (90, 16), (99, 16)] },
+    )]
+    fn summary(values: Vec<u64>, mean: f64, median: f64, percentiles: Vec<(u8, u64)>) {
+        let min = *values.iter().min().unwrap();
+        let max = *values.iter().max().unwrap();
+
+        let summary = Summary::new(values).unwrap();
+
+        assert_eq!(summary, Summary { min, max, mean, median, percentiles });
+    }
+
+    #[test]
+    fn summary_empty() {
+        assert_eq!(Summary::new(Vec::new()), None);
+    }
+
+    #[parameterized(
+        spread  = { &[0, 9, 10, 99],   0,  10, &[2, 1, 0, 0, 0, 0, 0, 0, 0, 1] },
+        narrow  = { &[5, 6, 7],        5,  1,  &[1, 1, 1, 0, 0, 0, 0, 0, 0, 0] },
+        same    = { &[3, 3],           3,  1,  &[2, 0, 0, 0, 0, 0, 0, 0, 0, 0] },
+    )]
+    fn histogram(calories: &[u32], start: u32, width: u32, counts: &[usize]) {
+        let histogram = Histogram::new(calories).unwrap();
+
+        assert_eq!(
+            histogram,
+            Histogram {
+                start,
+                width,
+                counts: counts.to_vec()
+            }
+        );
+    }
+
+    #[test]
+    fn collector_example() {
+        const INPUT: &str = include_str!("inputs/example.txt");
+
+        let mut collector = Collector::default();
+        for elf in elves(INPUT, &Config::default()) {
+            collector.push(&elf.unwrap());
+        }
+
+        assert_eq!(
+            collector.finish().unwrap(),
+            Statistics {
+                elves: 5,
+                calories: Summary {
+                    min: 4000,
+                    max: 24000,
+                    mean: 11000.0,
+                    median: 10000.0,
+                    percentiles: vec![
+                        (10, 4000),
+                        (25, 6000),
+                        (75, 11000),
+                        (90, 24000),
+                        (99, 24000)
+                    ],
+                },
+                items: Summary {
+                    min: 1,
+                    max: 3,
+                    mean: 2.0,
+                    median: 2.0,
+                    percentiles: vec![(10, 1), (25, 1), (75, 3), (90, 3), (99, 3)],
+                },
+                histogram: Histogram {
+                    start: 4000,
+                    width: 2001,
+                    counts: vec![2, 0, 1, 1, 0, 0, 0, 0, 0, 1],
+                },
+            }
+        );
+        assert_eq!(Collector::default().finish(), None);
+    }
+
+    #[parameterized(
+        single  = { &[(7, 7)],                      7.0, 7.0 },
+        even    = { &[(1, 1), (2, 1), (4, 2)],      2.75, 3.0 },
+        skewed  = { &[(1, 4), (16, 1)],             4.0, 1.0 },
+    )]
+    fn summary_from_counts(counts: &[(u64, usize)], mean: f64, median: f64) {
+        let counts: BTreeMap<_, _> = counts.iter().copied().collect();
+
+        let summary = Summary::from_counts(&counts).unwrap();
+
+        assert_eq!((summary.mean, summary.median), (mean, median));
+    }
+
+    #[test]
+    fn tagged_record() {
+        let elves: Vec<_> = elves("1\n2\n\n3", &Config::default())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let record = Record {
+            day: 1,
+            statistics: Statistics::new(&elves).unwrap(),
+        };
+
+        let json = serde_json::to_string(&record).unwrap();
+
+        assert!(
+            json.starts_with(r#"{"record":"statistics","day":1,"elves":2,"calories":{"min":3,"#),
+            "{json}"
+        );
+    }
+
+    #[test]
+    fn display_example() {
+        const INPUT: &str = include_str!("inputs/example.txt");
+
+        let elves: Vec<_> = elves(INPUT, &Config::default())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let statistics = Statistics::new(&elves).unwrap();
+
+        assert_eq!(
+            statistics.to_string(),
+            "\
+Elves: 5
+           Min    Max     Mean   Median   P10   P25    P75    P90    P99
+Calories  4000  24000  11000.0  10000.0  4000  6000  11000  24000  24000
+Items        1      3      2.0      2.0     1     1      3      3      3
+
+Calories histogram:
+  4000..6001  2  ########################################
+  6001..8002  0
+ 8002..10003  1  ####################
+10003..12004  1  ####################
+12004..14005  0
+14005..16006  0
+16006..18007  0
+18007..20008  0
+20008..22009  0
+22009..24010  1  ####################
+"
+        );
+    }
+}
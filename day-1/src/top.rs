@@ -0,0 +1,85 @@
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+};
+
+/// Keeps the `n` largest values pushed so far, in a min-heap that never holds more than `n`.
+#[derive(Clone, Debug)]
+pub struct TopN<T> {
+    n: usize,
+    heap: BinaryHeap<Reverse<T>>,
+}
+
+impl<T> TopN<T>
+where
+    T: Ord + Clone,
+{
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            heap: BinaryHeap::with_capacity(n + 1),
+        }
+    }
+
+    pub fn push(&mut self, value: T) {
+        if self.heap.len() < self.n {
+            self.heap.push(Reverse(value));
+        } else if let Some(mut smallest) = self.heap.peek_mut() {
+            if value > smallest.0 {
+                *smallest = Reverse(value);
+            }
+        }
+    }
+
+    /// Number of values kept, at most `n`.
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// The values kept, largest first.
+    pub fn largest(&self) -> Vec<T> {
+        let mut values: Vec<_> = self
+            .heap
+            .iter()
+            .map(|Reverse(value)| value.clone())
+            .collect();
+        values.sort_unstable_by(|a, b| b.cmp(a));
+        values
+    }
+}
+
+impl<T> Extend<T> for TopN<T>
+where
+    T: Ord + Clone,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| self.push(value));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use yare::parameterized;
+
+    use super::*;
+
+    #[parameterized(
+        empty       = { 3, &[],                     &[] },
+        fewer       = { 3, &[5, 1],                 &[5, 1] },
+        exact       = { 3, &[2, 3, 1],              &[3, 2, 1] },
+        more        = { 3, &[4, 9, 1, 7, 3, 8],     &[9, 8, 7] },
+        duplicates  = { 2, &[5, 5, 5, 1],           &[5, 5] },
+        none_kept   = { 0, &[1, 2],                 &[] },
+    )]
+    fn keeps_largest(n: usize, values: &[u32], expected: &[u32]) {
+        let mut top = TopN::new(n);
+
+        top.extend(values.iter().copied());
+
+        assert_eq!(top.largest(), expected);
+    }
+}
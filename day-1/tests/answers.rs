@@ -0,0 +1,7 @@
+use aoc_common::answers;
+use day_1::Day1;
+
+#[test]
+fn recorded_answers() {
+    answers::verify::<Day1>(concat!(env!("CARGO_MANIFEST_DIR"), "/src/inputs"));
+}
@@ -0,0 +1,24 @@
+use aoc_common::solution::Solution;
+use day_1::{
+    sum_batched_lined,
+    sum_n_largest,
+    Day1,
+};
+
+const EXAMPLE: &str = include_str!("../src/inputs/example.txt");
+
+#[test]
+fn example_top_elves() {
+    let mut sums: Vec<_> = sum_batched_lined(EXAMPLE).collect();
+
+    assert_eq!(sum_n_largest(&mut sums, 1), 24000);
+    assert_eq!(sum_n_largest(&mut sums, 3), 45000);
+}
+
+#[test]
+fn example_solution() {
+    let sums = Day1::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day1::part_1(&sums).unwrap(), 24000);
+    assert_eq!(Day1::part_2(&sums).unwrap(), 45000);
+}
@@ -0,0 +1,77 @@
+use aoc_common::{
+    bench::{
+        bench_generated,
+        bench_solution,
+    },
+    solution::Solution,
+};
+use criterion::{
+    criterion_group,
+    criterion_main,
+    BatchSize,
+    Criterion,
+};
+use day_2::{
+    adaptive,
+    compute_score,
+    compute_scores,
+    registry::Registry,
+    strategy::{
+        FixedMatch,
+        Roshambo,
+    },
+    Day2,
+};
+
+fn synthetic() -> String {
+    format!("{}\n", Day2::INPUT.trim_end()).repeat(200)
+}
+
+fn solution(c: &mut Criterion) {
+    let synthetic = synthetic();
+    bench_solution::<Day2>(c, &[("given", Day2::INPUT), ("synthetic", &synthetic)]);
+}
+
+fn generated(c: &mut Criterion) {
+    bench_generated::<Day2>(c, &[1_000, 10_000, 100_000]);
+}
+
+fn combined(c: &mut Criterion) {
+    c.bench_function("day-2/combined/given", |b| {
+        b.iter(|| compute_score::<(Roshambo, FixedMatch)>(Day2::INPUT))
+    });
+}
+
+fn dynamic(c: &mut Criterion) {
+    let registry = Registry::default();
+    let strategies = registry.select(&["roshambo", "fixed"]).unwrap();
+
+    c.bench_function("day-2/dynamic/given", |b| {
+        b.iter(|| compute_scores(Day2::INPUT, &strategies))
+    });
+}
+
+fn predictors(c: &mut Criterion) {
+    let opponent = adaptive::opponent(Day2::INPUT);
+    let mut group = c.benchmark_group("day-2/adaptive");
+
+    for (name, _) in adaptive::all() {
+        group.bench_function(name, |b| {
+            b.iter_batched(
+                || {
+                    adaptive::all()
+                        .into_iter()
+                        .find(|(n, _)| *n == name)
+                        .unwrap()
+                        .1
+                },
+                |mut strategy| adaptive::play_against(strategy.as_mut(), opponent.iter().copied()),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, solution, generated, combined, dynamic, predictors);
+criterion_main!(benches);
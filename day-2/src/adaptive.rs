@@ -0,0 +1,292 @@
+//! Strategies learning from the shapes the opponent played so far, to predict their next one and
+//! play the shape beating it.
+
+use crate::{
+    cyclic::{
+        Rps,
+        Variant,
+    },
+    split_line,
+    stats::Stats,
+    strategy::{
+        Resolve,
+        Roshambo,
+        Round,
+    },
+    Outcome,
+    Shape,
+};
+
+/// Shape played before anything is known about the opponent.
+const OPENING: Shape = Shape::Rock;
+
+/// A strategy that picks its shape from the rounds played so far, unlike a [`Strategy`] which
+/// reads it from the guide.
+///
+/// [`Strategy`]: crate::strategy::Strategy
+pub trait Adaptive {
+    /// The shape to play in the next round.
+    fn play(&mut self) -> Shape;
+
+    /// Learns from the round just played.
+    fn observe(&mut self, round: Round);
+}
+
+/// Plays the opponent's shapes in order against the strategy, tallying the rounds from the
+/// strategy's side.
+pub fn play_against<A, I>(strategy: &mut A, opponent: I) -> Stats
+where
+    A: Adaptive + ?Sized,
+    I: IntoIterator<Item = Shape>,
+{
+    opponent
+        .into_iter()
+        .map(|theirs| {
+            let round = Roshambo::resolve(&(strategy.play(), theirs));
+            strategy.observe(round);
+            Stats::from(round)
+        })
+        .fold(Stats::default(), Stats::merge)
+}
+
+/// The shapes of the opponent column of the guide, skipping the lines that are not two symbols of
+/// the puzzle's game, like [`optimize`] does.
+///
+/// [`optimize`]: crate::optimize::optimize
+pub fn opponent(guide: &str) -> Vec<Shape> {
+    let game = Rps::GAME;
+    guide
+        .lines()
+        .filter_map(split_line)
+        .filter(|&(_, response)| game.response(response).is_some())
+        .filter_map(|(this, _)| game.opponent(this))
+        .map(|shape| Shape::ALL[shape])
+        .collect()
+}
+
+/// The shape with the highest count, the earliest one among equals, if any was counted.
+fn most_likely(counts: &[u32; 3]) -> Option<Shape> {
+    Shape::ALL
+        .into_iter()
+        .zip(counts)
+        .filter(|&(_, &count)| count > 0)
+        .fold(
+            None,
+            |best: Option<(Shape, u32)>, (shape, &count)| match best {
+                Some((_, most)) if most >= count => best,
+                _ => Some((shape, count)),
+            },
+        )
+        .map(|(shape, _)| shape)
+}
+
+fn counter(predicted: Option<Shape>) -> Shape {
+    predicted.map_or(OPENING, |shape| shape.solve_for(Outcome::Win))
+}
+
+/// Expects the opponent to play the shape they played the most so far.
+#[derive(Clone, Debug, Default)]
+pub struct Frequency {
+    counts: [u32; 3],
+}
+
+impl Adaptive for Frequency {
+    fn play(&mut self) -> Shape {
+        counter(most_likely(&self.counts))
+    }
+
+    fn observe(&mut self, round: Round) {
+        self.counts[round.theirs as usize] += 1;
+    }
+}
+
+/// Expects the opponent to play the shape they played the most after their last `order` shapes,
+/// falling back on [`Frequency`] until that sequence was seen.
+#[derive(Clone, Debug)]
+pub struct Markov {
+    order: u32,
+    /// Counts of the shapes following each sequence, indexed by the sequence in base 3.
+    transitions: Vec<[u32; 3]>,
+    /// The last `order` shapes of the opponent, in base 3.
+    history: usize,
+    seen: u32,
+    frequency: Frequency,
+}
+
+impl Markov {
+    /// # Panics
+    /// If the order is 0, or so large that the counts of all the sequences of that many shapes do
+    /// not fit in memory.
+    pub fn new(order: u32) -> Self {
+        assert!(
+            order > 0,
+            "a Markov predictor needs at least one past shape"
+        );
+
+        Self {
+            order,
+            transitions: vec![[0; 3]; 3_usize.pow(order)],
+            history: 0,
+            seen: 0,
+            frequency: Frequency::default(),
+        }
+    }
+}
+
+impl Adaptive for Markov {
+    fn play(&mut self) -> Shape {
+        let predicted = (self.seen >= self.order)
+            .then(|| most_likely(&self.transitions[self.history]))
+            .flatten();
+
+        match predicted {
+            Some(shape) => counter(Some(shape)),
+            None => self.frequency.play(),
+        }
+    }
+
+    fn observe(&mut self, round: Round) {
+        let theirs = round.theirs as usize;
+        if self.seen >= self.order {
+            self.transitions[self.history][theirs] += 1;
+        }
+
+        self.history = (self.history * 3 + theirs) % self.transitions.len();
+        self.seen = self.seen.saturating_add(1);
+        self.frequency.observe(round);
+    }
+}
+
+/// Keeps its shape after a win or a draw, and switches to the shape beating the opponent's last one
+/// after a loss.
+#[derive(Clone, Debug)]
+pub struct WinStayLoseShift {
+    next: Shape,
+}
+
+impl Default for WinStayLoseShift {
+    fn default() -> Self {
+        Self { next: OPENING }
+    }
+}
+
+impl Adaptive for WinStayLoseShift {
+    fn play(&mut self) -> Shape {
+        self.next
+    }
+
+    fn observe(&mut self, round: Round) {
+        if round.outcome == Outcome::Lose {
+            self.next = round.theirs.solve_for(Outcome::Win);
+        }
+    }
+}
+
+/// Every adaptive strategy by name, freshly started.
+pub fn all() -> Vec<(&'static str, Box<dyn Adaptive>)> {
+    vec![
+        ("frequency", Box::new(Frequency::default())),
+        ("markov-1", Box::new(Markov::new(1))),
+        ("markov-2", Box::new(Markov::new(2))),
+        ("markov-3", Box::new(Markov::new(3))),
+        ("win-stay-lose-shift", Box::new(WinStayLoseShift::default())),
+    ]
+}
+
+#[cfg(test)]
+mod test {
+    use yare::parameterized;
+
+    use super::*;
+    use crate::Shape::*;
+
+    fn cycle(rounds: usize) -> impl Iterator<Item = Shape> {
+        Shape::ALL.into_iter().cycle().take(rounds)
+    }
+
+    #[parameterized(
+        none        = { [0, 0, 0], None },
+        single      = { [0, 2, 0], Some(Paper) },
+        highest     = { [1, 3, 2], Some(Paper) },
+        tied        = { [0, 2, 2], Some(Paper) },
+    )]
+    fn most_likely_shape(counts: [u32; 3], expected: Option<Shape>) {
+        assert_eq!(most_likely(&counts), expected);
+    }
+
+    #[test]
+    fn frequency_beats_constant_opponent() {
+        let stats = play_against(&mut Frequency::default(), [Scissors; 10]);
+
+        // Rock opens and wins, and stays the counter to the only shape counted.
+        assert_eq!((stats.wins, stats.draws, stats.losses), (10, 0, 0));
+        assert_eq!(stats.ours[Rock as usize], 10);
+    }
+
+    #[test]
+    fn frequency_follows_the_majority() {
+        let mut frequency = Frequency::default();
+
+        play_against(&mut frequency, [Rock, Paper, Paper]);
+
+        assert_eq!(frequency.play(), Scissors);
+    }
+
+    #[test]
+    fn markov_learns_cycles() {
+        let stats = play_against(&mut Markov::new(1), cycle(300));
+
+        // Every transition of the cycle is learned within its first 4 rounds.
+        assert!(stats.wins >= 296, "{stats:?}");
+    }
+
+    #[test]
+    fn markov_beats_frequency_on_cycles() {
+        let markov = play_against(&mut Markov::new(2), cycle(300));
+        let frequency = play_against(&mut Frequency::default(), cycle(300));
+
+        assert!(markov.score > frequency.score);
+    }
+
+    #[test]
+    fn markov_falls_back_on_frequency() {
+        let mut markov = Markov::new(3);
+
+        play_against(&mut markov, [Paper, Paper]);
+
+        assert_eq!(markov.play(), Scissors);
+    }
+
+    #[test]
+    fn win_stay_lose_shift() {
+        let mut strategy = WinStayLoseShift::default();
+        let mut played = Vec::new();
+
+        for theirs in [Scissors, Paper, Paper, Rock, Rock] {
+            let round = Roshambo::resolve(&(strategy.play(), theirs));
+            played.push(round.ours);
+            strategy.observe(round);
+        }
+
+        assert_eq!(played, [Rock, Rock, Scissors, Scissors, Paper]);
+    }
+
+    #[test]
+    fn opponent_skips_invalid_rounds() {
+        let shapes = opponent("A Y\nB\nC Q\nD X\n\nC Z\n");
+
+        assert_eq!(shapes, [Rock, Scissors]);
+    }
+
+    #[test]
+    fn every_strategy_plays_the_given_guide() {
+        const INPUT: &str = include_str!("inputs/given.txt");
+        let opponent = opponent(INPUT);
+
+        for (name, mut strategy) in all() {
+            let stats = play_against(strategy.as_mut(), opponent.iter().copied());
+
+            assert_eq!(stats.rounds() as usize, INPUT.lines().count(), "{name}");
+        }
+    }
+}
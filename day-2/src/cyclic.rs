@@ -0,0 +1,208 @@
+//! Rock paper scissors generalized to any odd number of shapes, such as rock paper scissors lizard
+//! Spock.
+//!
+//! Shapes are numbered from 0 and each beats the shapes an odd number of steps before it around the
+//! cycle, so that every shape beats as many shapes as it loses to. A shape scores its number plus
+//! one, like the puzzle's rock, paper and scissors.
+//!
+//! Guides of a game with `n` shapes write the opponent's shapes with the first `n` letters of the
+//! alphabet and the responses with the last `n`, which for three shapes are the puzzle's `A B C`
+//! and `X Y Z`.
+
+use crate::Outcome;
+
+/// A cyclic game, given by the names of its shapes in cycle order.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Game {
+    names: &'static [&'static str],
+}
+
+impl Game {
+    /// The puzzle's own game.
+    pub const RPS: Game = Game::new(&["rock", "paper", "scissors"]);
+    /// Rock paper scissors with seven shapes, as in RPS-7.
+    pub const RPS7: Game = Game::new(&[
+        "rock", "paper", "fire", "air", "scissors", "water", "sponge",
+    ]);
+    /// Rock paper scissors lizard Spock.
+    pub const RPSLS: Game = Game::new(&["rock", "paper", "scissors", "spock", "lizard"]);
+
+    /// # Panics
+    /// If there are fewer than 3 shapes, or an even number of them, which would leave some shapes
+    /// beating more shapes than they lose to.
+    pub const fn new(names: &'static [&'static str]) -> Self {
+        assert!(
+            names.len() >= 3 && names.len() % 2 == 1,
+            "a cyclic game needs an odd number of shapes, at least 3"
+        );
+        assert!(names.len() <= 13, "guides only have letters for 13 shapes");
+
+        Self { names }
+    }
+
+    /// Number of shapes of the game.
+    pub fn shapes(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn name(&self, shape: usize) -> &'static str {
+        self.names[shape]
+    }
+
+    pub fn score(&self, shape: usize) -> u32 {
+        debug_assert!(shape < self.shapes());
+        shape as u32 + 1
+    }
+
+    /// The outcome of playing `shape` against `other`.
+    pub fn outcome(&self, shape: usize, other: usize) -> Outcome {
+        match (shape + self.shapes() - other) % self.shapes() {
+            0 => Outcome::Draw,
+            steps if steps % 2 == 1 => Outcome::Win,
+            _ => Outcome::Lose,
+        }
+    }
+
+    /// The shape to play against `other` to reach the outcome, the closest one in the cycle when
+    /// several do.
+    pub fn solve_for(&self, other: usize, result: Outcome) -> usize {
+        let steps = match result {
+            Outcome::Draw => 0,
+            Outcome::Win => 1,
+            Outcome::Lose => self.shapes() - 1,
+        };
+        (other + steps) % self.shapes()
+    }
+
+    /// The shape the letter stands for in the opponent column of a guide.
+    pub fn opponent(&self, letter: u8) -> Option<usize> {
+        let shape = letter.checked_sub(b'A')? as usize;
+        (shape < self.shapes()).then_some(shape)
+    }
+
+    /// The shape the letter stands for in the response column of a guide.
+    pub fn response(&self, letter: u8) -> Option<usize> {
+        let first = b'Z' + 1 - self.shapes() as u8;
+        let shape = letter.checked_sub(first)? as usize;
+        (shape < self.shapes()).then_some(shape)
+    }
+}
+
+/// A cyclic game known at compile time, to pick the game a strategy plays.
+pub trait Variant {
+    const GAME: Game;
+}
+
+pub struct Rps;
+
+impl Variant for Rps {
+    const GAME: Game = Game::RPS;
+}
+
+pub struct Rpsls;
+
+impl Variant for Rpsls {
+    const GAME: Game = Game::RPSLS;
+}
+
+pub struct Rps7;
+
+impl Variant for Rps7 {
+    const GAME: Game = Game::RPS7;
+}
+
+#[cfg(test)]
+mod test {
+    use yare::parameterized;
+
+    use super::*;
+    use crate::Shape;
+
+    #[parameterized(
+        rps     = { Game::RPS },
+        rpsls   = { Game::RPSLS },
+        rps7    = { Game::RPS7 },
+    )]
+    fn balanced(game: Game) {
+        for shape in 0..game.shapes() {
+            let wins = (0..game.shapes())
+                .filter(|&other| game.outcome(shape, other) == Outcome::Win)
+                .count();
+            let losses = (0..game.shapes())
+                .filter(|&other| game.outcome(other, shape) == Outcome::Win)
+                .count();
+
+            assert_eq!(wins, game.shapes() / 2);
+            assert_eq!(losses, game.shapes() / 2);
+        }
+    }
+
+    #[parameterized(
+        rps     = { Game::RPS },
+        rpsls   = { Game::RPSLS },
+        rps7    = { Game::RPS7 },
+    )]
+    fn solve_for_reaches_outcome(game: Game) {
+        for other in 0..game.shapes() {
+            for result in [Outcome::Lose, Outcome::Draw, Outcome::Win] {
+                let shape = game.solve_for(other, result);
+
+                assert_eq!(game.outcome(shape, other), result);
+            }
+        }
+    }
+
+    #[test]
+    fn rps_matches_shapes() {
+        let shapes = [Shape::Rock, Shape::Paper, Shape::Scissors];
+
+        for (i, a) in shapes.into_iter().enumerate() {
+            assert_eq!(Game::RPS.score(i), a.score());
+            for (j, b) in shapes.into_iter().enumerate() {
+                let result = Game::RPS.outcome(i, j);
+                assert_eq!(b.solve_for(result), a);
+                assert_eq!(Game::RPS.solve_for(j, result), i);
+            }
+        }
+    }
+
+    #[parameterized(
+        paper_covers_rock       = { "paper",    "rock" },
+        scissors_cut_paper      = { "scissors", "paper" },
+        rock_crushes_lizard     = { "rock",     "lizard" },
+        lizard_poisons_spock    = { "lizard",   "spock" },
+        spock_smashes_scissors  = { "spock",    "scissors" },
+        scissors_decapitate     = { "scissors", "lizard" },
+        lizard_eats_paper       = { "lizard",   "paper" },
+        paper_disproves_spock   = { "paper",    "spock" },
+        spock_vaporizes_rock    = { "spock",    "rock" },
+        rock_crushes_scissors   = { "rock",     "scissors" },
+    )]
+    fn rpsls_rules(winner: &str, loser: &str) {
+        let game = Game::RPSLS;
+        let shape = |name| (0..game.shapes()).find(|&s| game.name(s) == name).unwrap();
+
+        assert_eq!(game.outcome(shape(winner), shape(loser)), Outcome::Win);
+        assert_eq!(game.outcome(shape(loser), shape(winner)), Outcome::Lose);
+    }
+
+    #[parameterized(
+        rps_first       = { Game::RPS,   b'A', b'X', Some(0), Some(0) },
+        rps_last        = { Game::RPS,   b'C', b'Z', Some(2), Some(2) },
+        rps_outside     = { Game::RPS,   b'D', b'W', None,    None },
+        rpsls_first     = { Game::RPSLS, b'A', b'V', Some(0), Some(0) },
+        rpsls_last      = { Game::RPSLS, b'E', b'Z', Some(4), Some(4) },
+        rpsls_outside   = { Game::RPSLS, b'F', b'U', None,    None },
+        lowercase       = { Game::RPS,   b'a', b'x', None,    None },
+    )]
+    fn letters(
+        game: Game,
+        opponent: u8,
+        response: u8,
+        expected_opponent: Option<usize>,
+        expected_response: Option<usize>,
+    ) {
+        assert_eq!(game.opponent(opponent), expected_opponent);
+        assert_eq!(game.response(response), expected_response);
+    }
+}
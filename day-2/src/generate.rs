@@ -0,0 +1,47 @@
+use std::fmt::Write;
+
+use aoc_common::generate::{
+    rand::{
+        seq::SliceRandom,
+        Rng,
+    },
+    Generator,
+};
+
+use crate::Day2;
+
+impl Generator for Day2 {
+    /// Generates a strategy guide of `size` rounds.
+    fn generate<R>(rng: &mut R, size: usize) -> String
+    where
+        R: Rng,
+    {
+        let mut input = String::new();
+        for _ in 0..size {
+            let opponent = b"ABC".choose(rng).copied().map(char::from).unwrap();
+            let response = b"XYZ".choose(rng).copied().map(char::from).unwrap();
+            writeln!(input, "{opponent} {response}").unwrap();
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{
+        generate::seeded,
+        solution::solve,
+    };
+
+    use super::*;
+
+    #[test]
+    fn generated_inputs_solve() {
+        for (seed, size) in [(0, 0), (1, 1), (2, 100), (3, 10_000)] {
+            let input = seeded::<Day2>(seed, size);
+
+            assert_eq!(input.lines().count(), size);
+            assert!(solve::<Day2>(&input, None).is_ok());
+        }
+    }
+}
@@ -0,0 +1,386 @@
+use std::mem;
+
+use aoc_common::solution::Solution;
+use snafu::prelude::*;
+use strategy::{
+    DynStrategy,
+    FixedMatch,
+    Roshambo,
+    Strategy,
+};
+use Outcome::*;
+use Shape::*;
+
+pub mod adaptive;
+pub mod cyclic;
+pub mod generate;
+pub mod mixed;
+pub mod optimize;
+pub mod registry;
+pub mod stats;
+pub mod strategy;
+pub mod symbols;
+pub mod tournament;
+
+#[non_exhaustive]
+#[derive(Debug, Snafu)]
+#[snafu(module(error), context(suffix(false)))]
+pub enum Error {
+    #[snafu(display("'{}' is not {expected}", byte.escape_ascii()))]
+    UnknownSymbol { byte: u8, expected: &'static str },
+    #[snafu(display("line {line} is not two symbols separated by a space: '{content}'"))]
+    MalformedLine { line: usize, content: String },
+    #[snafu(display("line {line} cannot be scored, {source}: '{content}'"))]
+    InvalidLine {
+        line: usize,
+        content: String,
+        #[snafu(source(from(Error, Box::new)))]
+        source: Box<Error>,
+    },
+}
+
+/// How to handle the lines of the guide that are not two known symbols.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum Parsing {
+    /// Fail on the first such line, with its number.
+    #[default]
+    Strict,
+    /// Skip such lines, reporting them along with the score.
+    Lenient,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl TryFrom<u8> for Outcome {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            b'X' => Ok(Lose),
+            b'Y' => Ok(Draw),
+            b'Z' => Ok(Win),
+            _ => error::UnknownSymbol { byte: value, expected: "an outcome" }.fail(),
+        }
+    }
+}
+
+impl Outcome {
+    pub fn score(self) -> u32 {
+        match self {
+            Lose => 0,
+            Draw => 3,
+            Win => 6,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl TryFrom<u8> for Shape {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            b'A' | b'X' => Ok(Rock),
+            b'B' | b'Y' => Ok(Paper),
+            b'C' | b'Z' => Ok(Scissors),
+            _ => error::UnknownSymbol { byte: value, expected: "a shape" }.fail(),
+        }
+    }
+}
+
+impl Shape {
+    /// Every shape, in the order of their scores.
+    pub const ALL: [Shape; 3] = [Rock, Paper, Scissors];
+
+    pub fn score(self) -> u32 {
+        match self {
+            Rock => 1,
+            Paper => 2,
+            Scissors => 3,
+        }
+    }
+
+    pub fn solve_for(self, result: Outcome) -> Self {
+        match (self, result) {
+            (Rock, Draw) | (Scissors, Win) | (Paper, Lose) => Rock,
+            (Paper, Draw) | (Rock, Win) | (Scissors, Lose) => Paper,
+            (Scissors, Draw) | (Paper, Win) | (Rock, Lose) => Scissors,
+        }
+    }
+}
+
+/// Splits a line of the guide into its two symbols, ignoring trailing whitespace.
+pub fn split_line(line: &str) -> Option<(u8, u8)> {
+    match *line.trim_end().as_bytes() {
+        [this, b' ', other] => Some((this, other)),
+        _ => None,
+    }
+}
+
+/// Scores the whole strategy guide, interpreting each line with the given strategy and skipping the
+/// lines it cannot read, see [`score_guide`] to have them reported instead.
+pub fn compute_score<S>(input: &str) -> S::Out
+where
+    S: Strategy,
+{
+    input
+        .lines()
+        .filter_map(split_line)
+        .filter_map(|line| S::parse_match(line).ok())
+        .map(S::match_score)
+        .fold(S::Out::default(), S::reduce)
+}
+
+/// The score of a guide, with the lines skipped to reach it.
+#[derive(Debug)]
+pub struct Scored<T> {
+    pub score: T,
+    pub skipped: Vec<Error>,
+}
+
+/// Scores the whole strategy guide like [`compute_score`], handling the lines the strategy cannot
+/// read as told. Blank lines are always skipped.
+pub fn score_guide<S>(input: &str, parsing: Parsing) -> Result<Scored<S::Out>, Error>
+where
+    S: Strategy,
+{
+    let mut score = S::Out::default();
+    let skipped = read_guide(input, parsing, |symbols| {
+        let round = S::parse_match(symbols)?;
+        score = S::reduce(mem::take(&mut score), S::match_score(round));
+        Ok(())
+    })?;
+
+    Ok(Scored { score, skipped })
+}
+
+/// Checks that each of the strategies can read every line of the guide, handling the lines some
+/// cannot read as told, see [`compute_scores`] to score them. Blank lines are always skipped.
+pub fn check_guide(
+    input: &str,
+    strategies: &[&dyn DynStrategy],
+    parsing: Parsing,
+) -> Result<Vec<Error>, Error> {
+    read_guide(input, parsing, |symbols| {
+        strategies
+            .iter()
+            .try_for_each(|strategy| strategy.check(symbols))
+    })
+}
+
+/// Reads every line of the guide that is not blank, returning the lines skipped in lenient parsing.
+fn read_guide<F>(input: &str, parsing: Parsing, mut read: F) -> Result<Vec<Error>, Error>
+where
+    F: FnMut((u8, u8)) -> Result<(), Error>,
+{
+    let mut skipped = Vec::new();
+
+    for (content, line) in input.lines().zip(1_usize..) {
+        if content.trim().is_empty() {
+            continue;
+        }
+
+        let round = split_line(content)
+            .context(error::MalformedLine { line, content })
+            .and_then(|symbols| read(symbols).context(error::InvalidLine { line, content }));
+        match round {
+            Ok(()) => {}
+            Err(error) if parsing == Parsing::Lenient => skipped.push(error),
+            Err(error) => return Err(error),
+        }
+    }
+
+    Ok(skipped)
+}
+
+/// Scores the whole strategy guide once for each of the strategies, in a single pass over it. Unlike
+/// with a tuple of strategies, a line only one strategy reads still counts for that one.
+pub fn compute_scores(input: &str, strategies: &[&dyn DynStrategy]) -> Vec<u32> {
+    let mut scores = vec![0; strategies.len()];
+
+    for line in input.lines().filter_map(split_line) {
+        for (score, strategy) in scores.iter_mut().zip(strategies) {
+            if let Some(line_score) = strategy.line_score(line) {
+                *score = strategy.reduce(*score, line_score);
+            }
+        }
+    }
+
+    scores
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Answer1 = u32;
+    type Answer2 = u32;
+    type Error = Error;
+    type Parsed<'i> = &'i str;
+
+    const DAY: u8 = 2;
+    const INPUT: &'static str = include_str!("inputs/given.txt");
+
+    /// Checks that every line of the guide can be scored both ways.
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+        score_guide::<(Roshambo, FixedMatch)>(input, Parsing::Strict)?;
+        Ok(input)
+    }
+
+    fn part_1(guide: &Self::Parsed<'_>) -> Result<Self::Answer1, Self::Error> {
+        Ok(compute_score::<Roshambo>(guide))
+    }
+
+    fn part_2(guide: &Self::Parsed<'_>) -> Result<Self::Answer2, Self::Error> {
+        Ok(compute_score::<FixedMatch>(guide))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use yare::parameterized;
+
+    use super::*;
+    use crate::{
+        cyclic::Rpsls,
+        strategy::{
+            Cyclic,
+            Erased,
+        },
+    };
+
+    #[test]
+    fn one_round() {
+        const INPUT: &str = "A X";
+
+        let total_score = compute_score::<(Roshambo, FixedMatch)>(INPUT);
+
+        let roshambo = Draw.score() + Rock.score();
+        let fixed = Lose.score() + Scissors.score();
+        assert_eq!(total_score, (roshambo, fixed))
+    }
+
+    #[test]
+    fn example_works_one() {
+        const INPUT: &str = include_str!("inputs/example.txt");
+
+        let total_score = compute_score::<Roshambo>(INPUT);
+
+        assert_eq!(total_score, 15)
+    }
+
+    #[test]
+    fn example_works_two() {
+        const INPUT: &str = include_str!("inputs/example.txt");
+
+        let total_score = compute_score::<FixedMatch>(INPUT);
+
+        assert_eq!(total_score, 12)
+    }
+
+    #[test]
+    fn compute_score_skips_unreadable_lines() {
+        const INPUT: &str = "A\nA Y\n\nD Y\nA Y Z\nB X\r\n";
+
+        let total_score = compute_score::<Roshambo>(INPUT);
+
+        assert_eq!(total_score, (2 + 6) + 1);
+    }
+
+    #[parameterized(
+        short           = { "A",        None },
+        no_space        = { "AY",       None },
+        extra_symbol    = { "A Y Z",    None },
+        opponent        = { "D Y",      Some(b'D') },
+        response        = { "A W",      Some(b'W') },
+        lowercase       = { "a y",      Some(b'a') },
+    )]
+    fn strict_fails(line: &str, unknown: Option<u8>) {
+        let input = format!("A Y\n{line}\nB X");
+
+        let error = score_guide::<Roshambo>(&input, Parsing::Strict).unwrap_err();
+
+        match (error, unknown) {
+            (Error::MalformedLine { line: 2, content }, None) => assert_eq!(content, line),
+            (Error::InvalidLine { line: 2, content, source }, Some(unknown)) => {
+                assert_eq!(content, line);
+                assert!(matches!(*source, Error::UnknownSymbol { byte, .. } if byte == unknown));
+            }
+            (error, _) => panic!("unexpected error: {error}"),
+        }
+    }
+
+    #[test]
+    fn strict_accepts_crlf_and_blank_lines() {
+        const INPUT: &str = "A Y\r\n\r\nB X \r\n";
+
+        let scored = score_guide::<(Roshambo, FixedMatch)>(INPUT, Parsing::Strict).unwrap();
+
+        assert_eq!(scored.score, compute_score::<(Roshambo, FixedMatch)>(INPUT));
+        assert!(scored.skipped.is_empty());
+    }
+
+    #[test]
+    fn lenient_reports_skipped_lines() {
+        const INPUT: &str = "A Y\nA\nD Y\nB X";
+
+        let scored = score_guide::<Roshambo>(INPUT, Parsing::Lenient).unwrap();
+
+        assert_eq!(scored.score, compute_score::<Roshambo>(INPUT));
+        let lines: Vec<_> = scored
+            .skipped
+            .iter()
+            .map(|error| match error {
+                Error::MalformedLine { line, .. } | Error::InvalidLine { line, .. } => *line,
+                error => panic!("unexpected error: {error}"),
+            })
+            .collect();
+        assert_eq!(lines, [2, 3]);
+    }
+
+    #[test]
+    fn check_guide_for_strategies() {
+        const INPUT: &str = "A V\n\nE Z\nD W";
+        let rpsls = Erased::<Cyclic<Rpsls>>::default();
+        let roshambo = Erased::<Roshambo>::default();
+
+        let skipped = check_guide(INPUT, &[&rpsls], Parsing::Strict).unwrap();
+        let error = check_guide(INPUT, &[&rpsls, &roshambo], Parsing::Strict).unwrap_err();
+        let lenient = check_guide(INPUT, &[&roshambo, &rpsls], Parsing::Lenient).unwrap();
+
+        assert!(skipped.is_empty());
+        assert!(matches!(error, Error::InvalidLine { line: 1, .. }));
+        assert_eq!(lenient.len(), 3);
+    }
+
+    #[test]
+    fn error_messages() {
+        let error = score_guide::<FixedMatch>("A Y\nB \u{e9}", Parsing::Strict).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "line 2 is not two symbols separated by a space: 'B \u{e9}'"
+        );
+        let error = score_guide::<FixedMatch>("A Y\nB W", Parsing::Strict).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2 cannot be scored, 'W' is not an outcome: 'B W'"
+        );
+    }
+
+    #[test]
+    fn parse_rejects_bad_guides() {
+        assert!(Day2::parse("A Y\nB").is_err());
+        assert!(Day2::parse("A Y\nB Z\n").is_ok());
+    }
+}
@@ -1,132 +1,298 @@
-use strategy::{
-    FixedMatch,
-    Roshambo,
-    Strategy,
+use std::{
+    error::Error,
+    fs,
+    path::{
+        Path,
+        PathBuf,
+    },
+    process::ExitCode,
 };
-use Outcome::*;
-use Shape::*;
 
-mod strategy;
+use aoc_common::{
+    input::{
+        Input,
+        Part,
+    },
+    output::{
+        self,
+        Format,
+    },
+    solution::Solution,
+};
+use clap::Parser;
+use day_2::{
+    adaptive,
+    check_guide,
+    compute_score,
+    compute_scores,
+    mixed::Evaluation,
+    optimize::Comparison,
+    registry::Registry,
+    score_guide,
+    stats::{
+        Tally,
+        Trace,
+    },
+    strategy::{
+        DynStrategy,
+        Erased,
+        FixedMatch,
+        Resolve,
+        Roshambo,
+    },
+    symbols::{
+        Symbols,
+        OPPONENT,
+        RESPONSE,
+    },
+    tournament::{
+        Player,
+        Tournament,
+    },
+    Day2,
+    Parsing,
+};
 
-#[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub enum Outcome {
-    Lose,
-    Draw,
-    Win,
-}
+#[derive(Debug, Parser)]
+struct Args {
+    #[command(flatten)]
+    input: Input,
+
+    /// Table of the symbols the guide uses for each column, instead of the puzzle's letters.
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["opponent", "response"])]
+    symbols: Option<PathBuf>,
+
+    /// Symbols the guide uses for the first column, in the order of `A,B,C`.
+    #[arg(long, value_name = "A,B,C", value_delimiter = ',')]
+    opponent: Option<Vec<String>>,
+
+    /// Symbols the guide uses for the second column, in the order of `X,Y,Z`.
+    #[arg(long, value_name = "X,Y,Z", value_delimiter = ',')]
+    response: Option<Vec<String>>,
+
+    /// Skip the lines that are not two known symbols instead of failing on them.
+    #[arg(long)]
+    lenient: bool,
+
+    /// Score the guide with each of the named strategies, such as `roshambo,fixed,rpsls`, checking
+    /// it only against them. The puzzle's parts then only run when picked with `--part`, or for the
+    /// other reports.
+    #[arg(long, value_name = "NAMES", value_delimiter = ',')]
+    strategies: Vec<String>,
+
+    /// Also play the guides of several players against each other, one file per player, reading
+    /// them the way of the part to run.
+    #[arg(long, value_name = "GUIDES", num_args = 2..)]
+    tournament: Vec<PathBuf>,
+
+    /// Also compare the scores with the best responses to the opponent's shapes.
+    #[arg(long)]
+    optimize: bool,
+
+    /// Also compare the guide with playing at random against the opponent's shapes.
+    #[arg(long)]
+    mixed: bool,
+
+    /// Also play the adaptive strategies against the opponent's shapes.
+    #[arg(long)]
+    adaptive: bool,
 
-impl TryFrom<u8> for Outcome {
-    type Error = ();
+    /// Also report the outcomes and shapes played over the whole guide.
+    #[arg(short, long)]
+    stats: bool,
 
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            b'X' => Ok(Lose),
-            b'Y' => Ok(Draw),
-            b'Z' => Ok(Win),
-            _ => Err(()),
+    /// Also list every round with its score and the score so far.
+    #[arg(short, long)]
+    trace: bool,
+}
+
+impl Args {
+    fn symbols(&self) -> Result<Symbols, Box<dyn Error>> {
+        if let Some(path) = &self.symbols {
+            return Ok(fs::read_to_string(path)?.parse()?);
         }
+
+        let default = |letters: [&str; 3]| letters.map(String::from).to_vec();
+        let opponent = self.opponent.clone().unwrap_or_else(|| default(OPPONENT));
+        let response = self.response.clone().unwrap_or_else(|| default(RESPONSE));
+        Ok(Symbols::new(&opponent, &response)?)
     }
 }
 
-impl Outcome {
-    pub fn score(self) -> u32 {
-        match self {
-            Lose => 0,
-            Draw => 3,
-            Win => 6,
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("Error: {error}");
+            ExitCode::FAILURE
         }
     }
 }
 
-#[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub enum Shape {
-    Rock,
-    Paper,
-    Scissors,
-}
+fn run() -> Result<(), Box<dyn Error>> {
+    let args = Args::parse();
+    let symbols = args.symbols()?;
+    let Args {
+        input: args,
+        lenient,
+        strategies,
+        tournament,
+        optimize,
+        mixed,
+        adaptive,
+        stats,
+        trace,
+        ..
+    } = args;
+    let registry = Registry::default();
+    let selected = registry.select(&strategies)?;
+    let parsing = if lenient {
+        Parsing::Lenient
+    } else {
+        Parsing::Strict
+    };
+    let input = args.read(Day2::INPUT)?;
+    let translation = symbols.translate(&input, parsing)?;
+    for skipped in &translation.skipped {
+        eprintln!("Skipped: {skipped}");
+    }
+    let input = translation.guide;
 
-impl TryFrom<u8> for Shape {
-    type Error = ();
+    if args.format != Format::Text {
+        text_only(&[
+            ("--stats", stats),
+            ("--trace", trace),
+            ("--strategies", !strategies.is_empty()),
+            ("--optimize", optimize),
+            ("--mixed", mixed),
+            ("--tournament", !tournament.is_empty()),
+            ("--adaptive", adaptive),
+        ])?;
+        return output::print_with::<Day2, _>(args.format, &input, args.part, |input| {
+            let scored = score_guide::<(Roshambo, FixedMatch)>(input, parsing)?;
+            for skipped in scored.skipped {
+                eprintln!("Skipped: {skipped}");
+            }
+            Ok(input)
+        });
+    }
 
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            b'A' | b'X' => Ok(Rock),
-            b'B' | b'Y' => Ok(Paper),
-            b'C' | b'Z' => Ok(Scissors),
-            _ => Err(()),
-        }
+    // The puzzle's parts and the analyses built on them read the guide as rock paper scissors,
+    // unlike the selected strategies which may play another game.
+    let puzzle = selected.is_empty()
+        || args.part.is_some()
+        || stats
+        || trace
+        || optimize
+        || mixed
+        || adaptive;
+    let (roshambo, fixed) = (
+        Erased::<Roshambo>::default(),
+        Erased::<FixedMatch>::default(),
+    );
+    let mut checked = selected.clone();
+    if puzzle {
+        checked.extend([&roshambo as &dyn DynStrategy, &fixed]);
     }
-}
+    for skipped in check_guide(&input, &checked, parsing)? {
+        eprintln!("Skipped: {skipped}");
+    }
+    let guide = &*input;
 
-impl Shape {
-    pub fn score(self) -> u32 {
-        match self {
-            Rock => 1,
-            Paper => 2,
-            Scissors => 3,
+    if puzzle && args.runs(Part::One) {
+        let score = Day2::part_1(&guide)?;
+        println!("The expected score for roshambo is {score}");
+        report::<Roshambo>(guide, stats, trace);
+    }
+    if puzzle && args.runs(Part::Two) {
+        let score = Day2::part_2(&guide)?;
+        println!("The expected score for fixed matches is {score}");
+        report::<FixedMatch>(guide, stats, trace);
+    }
+    if !selected.is_empty() {
+        if puzzle {
+            println!();
+        }
+        for (name, score) in strategies.iter().zip(compute_scores(&input, &selected)) {
+            println!("The score for {name} is {score}");
         }
     }
-
-    pub fn solve_for(self, result: Outcome) -> Self {
-        match (self, result) {
-            (Rock, Draw) | (Scissors, Win) | (Paper, Lose) => Rock,
-            (Paper, Draw) | (Rock, Win) | (Scissors, Lose) => Paper,
-            (Scissors, Draw) | (Paper, Win) | (Rock, Lose) => Scissors,
+    if !tournament.is_empty() {
+        let players = tournament
+            .iter()
+            .map(|path| player(path, &symbols, args.part, parsing))
+            .collect::<Result<_, _>>()?;
+        print!("\n{}", Tournament::play(players));
+    }
+    if optimize {
+        print!("\n{}", Comparison::new(&input));
+    }
+    if mixed {
+        match Evaluation::new(&input) {
+            Some(evaluation) => print!("\n{evaluation}"),
+            None => println!("\nThe guide has no round to evaluate."),
+        }
+    }
+    if adaptive {
+        let opponent = adaptive::opponent(&input);
+        println!();
+        for (name, mut strategy) in adaptive::all() {
+            let stats = adaptive::play_against(strategy.as_mut(), opponent.iter().copied());
+            println!(
+                "{name:<20}{:>8}  {} wins, {} draws, {} losses",
+                stats.score, stats.wins, stats.draws, stats.losses
+            );
         }
     }
-}
 
-pub fn compute_score<S>(input: &str) -> S::Out
-where
-    S: Strategy,
-{
-    input
-        .lines()
-        .map(str::as_bytes)
-        .map(|c| (c[0], c[2]))
-        .filter_map(S::parse_match)
-        .map(S::match_score)
-        .fold(S::Out::default(), S::reduce)
+    Ok(())
 }
 
-fn main() {
-    const INPUT: &str = include_str!("inputs/given.txt");
-
-    let (first, second) = compute_score::<(Roshambo, FixedMatch)>(INPUT);
-    println!("The expected score for roshambo is {first}");
-    println!("The expected score for fixed matches is {second}");
+/// Fails on the first of the given options, which are only printed as text.
+fn text_only(options: &[(&str, bool)]) -> Result<(), Box<dyn Error>> {
+    match options.iter().find(|&&(_, given)| given) {
+        Some((name, _)) => Err(format!("{name} is only printed as text").into()),
+        None => Ok(()),
+    }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    #[test]
-    fn one_round() {
-        const INPUT: &str = "A X";
-
-        let total_score = compute_score::<(Roshambo, FixedMatch)>(INPUT);
-
-        let roshambo = Draw.score() + Rock.score();
-        let fixed = Lose.score() + Scissors.score();
-        assert_eq!(total_score, (roshambo, fixed))
+/// Reads the guide of a player, named after its file.
+fn player(
+    path: &Path,
+    symbols: &Symbols,
+    part: Option<Part>,
+    parsing: Parsing,
+) -> Result<Player, Box<dyn Error>> {
+    let name = path.file_stem().map_or_else(
+        || path.display().to_string(),
+        |stem| stem.to_string_lossy().into_owned(),
+    );
+    let guide = fs::read_to_string(path)?;
+    let translation = symbols.translate(&guide, parsing)?;
+    for skipped in &translation.skipped {
+        eprintln!("Skipped in {name}: {skipped}");
     }
+    let guide = translation.guide;
 
-    #[test]
-    fn example_works_one() {
-        const INPUT: &str = include_str!("inputs/example.txt");
-
-        let total_score = compute_score::<Roshambo>(INPUT);
-
-        assert_eq!(total_score, 15)
+    let (player, skipped) = match part {
+        Some(Part::Two) => Player::from_guide::<FixedMatch>(name, &guide, parsing)?,
+        _ => Player::from_guide::<Roshambo>(name, &guide, parsing)?,
+    };
+    for skipped in skipped {
+        eprintln!("Skipped in {}: {skipped}", player.name);
     }
+    Ok(player)
+}
 
-    #[test]
-    fn example_works_two() {
-        const INPUT: &str = include_str!("inputs/example.txt");
-
-        let total_score = compute_score::<FixedMatch>(INPUT);
-
-        assert_eq!(total_score, 12)
+/// Prints the statistics and the rounds of the guide as the strategy plays it, when asked for.
+fn report<S>(guide: &str, stats: bool, trace: bool)
+where
+    S: Resolve,
+{
+    if stats {
+        print!("\n{}\n", compute_score::<Tally<S>>(guide));
+    }
+    if trace {
+        print!("\n{}\n", compute_score::<Trace<S>>(guide));
     }
 }
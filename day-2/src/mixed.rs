@@ -0,0 +1,404 @@
+//! Mixed strategies, where each player picks their shape at random with given probabilities.
+//!
+//! Both players score the puzzle's way, with the score of their shape plus the score of the outcome,
+//! so the game is not zero-sum and its equilibria are found by support enumeration.
+
+use std::{
+    fmt,
+    fmt::{
+        Display,
+        Formatter,
+    },
+};
+
+use aoc_common::generate::rand::Rng;
+
+use crate::{
+    compute_score,
+    stats::Tally,
+    strategy::{
+        FixedMatch,
+        Resolve,
+        Roshambo,
+    },
+    Shape,
+};
+
+/// Tolerance of the comparisons between probabilities and expected scores.
+const EPSILON: f64 = 1e-9;
+
+/// The score of playing `ours` against `theirs`.
+pub fn payoff(ours: Shape, theirs: Shape) -> u32 {
+    Roshambo::resolve(&(ours, theirs)).score()
+}
+
+/// The probability of playing each shape, indexed by [`Shape`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Mixed([f64; 3]);
+
+impl Mixed {
+    /// Every shape as likely.
+    pub const UNIFORM: Mixed = Mixed([1.0 / 3.0; 3]);
+
+    /// Plays each shape with a probability proportional to its weight, if the weights are
+    /// non-negative and not all zero.
+    pub fn new(weights: [f64; 3]) -> Option<Self> {
+        let total: f64 = weights.iter().sum();
+        let valid = weights.iter().all(|&weight| weight >= 0.0) && total > 0.0;
+
+        valid.then(|| Self(weights.map(|weight| weight / total)))
+    }
+
+    /// Always plays the shape.
+    pub fn pure(shape: Shape) -> Self {
+        let mut probabilities = [0.0; 3];
+        probabilities[shape as usize] = 1.0;
+        Self(probabilities)
+    }
+
+    /// Plays each shape as often as it appears, if any does.
+    pub fn frequencies<I>(shapes: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Shape>,
+    {
+        let mut counts = [0.0; 3];
+        shapes
+            .into_iter()
+            .for_each(|shape| counts[shape as usize] += 1.0);
+        Self::new(counts)
+    }
+
+    pub fn probability(&self, shape: Shape) -> f64 {
+        self.0[shape as usize]
+    }
+
+    pub fn sample<R>(&self, rng: &mut R) -> Shape
+    where
+        R: Rng + ?Sized,
+    {
+        let mut left: f64 = rng.gen();
+        for shape in Shape::ALL {
+            left -= self.probability(shape);
+            if left < 0.0 {
+                return shape;
+            }
+        }
+
+        // Rounding errors can leave a sliver of probability past the last shape.
+        Shape::ALL
+            .into_iter()
+            .rev()
+            .find(|&shape| self.probability(shape) > 0.0)
+            .unwrap()
+    }
+}
+
+impl Display for Mixed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let [rock, paper, scissors] = self.0.map(|p| p * 100.0);
+        write!(
+            f,
+            "rock {rock:.1}%, paper {paper:.1}%, scissors {scissors:.1}%"
+        )
+    }
+}
+
+/// The score we expect per round on average.
+pub fn expected_score(ours: &Mixed, theirs: &Mixed) -> f64 {
+    Shape::ALL
+        .into_iter()
+        .flat_map(|a| Shape::ALL.map(|b| (a, b)))
+        .map(|(a, b)| ours.probability(a) * theirs.probability(b) * f64::from(payoff(a, b)))
+        .sum()
+}
+
+/// Plays the given number of rounds at random, returning our average score per round.
+pub fn simulate<R>(ours: &Mixed, theirs: &Mixed, rounds: u32, rng: &mut R) -> f64
+where
+    R: Rng + ?Sized,
+{
+    let total: u32 = (0..rounds)
+        .map(|_| payoff(ours.sample(rng), theirs.sample(rng)))
+        .sum();
+    f64::from(total) / f64::from(rounds.max(1))
+}
+
+/// Strategies neither player gains anything from leaving alone.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Equilibrium {
+    pub ours: Mixed,
+    pub theirs: Mixed,
+    /// Our expected score per round.
+    pub score: f64,
+    /// The opponent's expected score per round.
+    pub their_score: f64,
+}
+
+/// Finds the Nash equilibria of the game, trying every pair of equally large sets of shapes each
+/// player may play.
+pub fn equilibria() -> Vec<Equilibrium> {
+    let ours = Shape::ALL.map(|a| Shape::ALL.map(|b| f64::from(payoff(a, b))));
+    // The opponent scores like us, with the roles swapped.
+    let theirs_transposed = ours;
+
+    let supports = || (1..8_u8).map(|mask| Shape::ALL.map(|s| mask & (1 << s as u8) != 0));
+    let mut equilibria = Vec::new();
+
+    for our_support in supports() {
+        for their_support in supports() {
+            let size = |support: [bool; 3]| support.iter().filter(|&&s| s).count();
+            if size(our_support) != size(their_support) {
+                continue;
+            }
+
+            // Their strategy makes us indifferent between our shapes, and the other way around.
+            let Some((theirs, score)) = indifferent(&ours, our_support, their_support) else {
+                continue;
+            };
+            let Some((ours_, their_score)) =
+                indifferent(&theirs_transposed, their_support, our_support)
+            else {
+                continue;
+            };
+
+            let best = |matrix: &[[f64; 3]; 3], other: &[f64; 3], value: f64| {
+                matrix.iter().all(|row| dot(row, other) <= value + EPSILON)
+            };
+            if best(&ours, &theirs, score) && best(&theirs_transposed, &ours_, their_score) {
+                equilibria.push(Equilibrium {
+                    ours: Mixed(ours_),
+                    theirs: Mixed(theirs),
+                    score,
+                    their_score,
+                });
+            }
+        }
+    }
+
+    equilibria
+}
+
+/// Solves for the probabilities of the `columns` that give every one of the `rows` the same value,
+/// returning them with that value if they are all valid probabilities.
+fn indifferent(
+    matrix: &[[f64; 3]; 3],
+    rows: [bool; 3],
+    columns: [bool; 3],
+) -> Option<([f64; 3], f64)> {
+    let rows: Vec<_> = (0..3).filter(|&i| rows[i]).collect();
+    let columns: Vec<_> = (0..3).filter(|&j| columns[j]).collect();
+    let size = columns.len() + 1;
+
+    // Unknowns are the probabilities of the columns, then the value, which every row reaches.
+    let mut system: Vec<Vec<f64>> = rows
+        .iter()
+        .map(|&i| {
+            let mut equation: Vec<_> = columns.iter().map(|&j| matrix[i][j]).collect();
+            equation.extend([-1.0, 0.0]);
+            equation
+        })
+        .collect();
+    let mut total = vec![1.0; columns.len()];
+    total.extend([0.0, 1.0]);
+    system.push(total);
+
+    let solution = solve(system, size)?;
+
+    let mut probabilities = [0.0; 3];
+    for (&j, &p) in columns.iter().zip(&solution) {
+        if p < -EPSILON {
+            return None;
+        }
+        probabilities[j] = p.max(0.0);
+    }
+    Some((probabilities, solution[size - 1]))
+}
+
+/// Solves the square linear system given as rows of coefficients followed by their constant, by
+/// Gaussian elimination with partial pivoting.
+fn solve(mut system: Vec<Vec<f64>>, size: usize) -> Option<Vec<f64>> {
+    for column in 0..size {
+        let pivot = (column..size)
+            .max_by(|&a, &b| system[a][column].abs().total_cmp(&system[b][column].abs()))?;
+        if system[pivot][column].abs() < EPSILON {
+            return None;
+        }
+        system.swap(column, pivot);
+
+        let pivot = system[column].clone();
+        for (i, row) in system.iter_mut().enumerate() {
+            if i != column {
+                let factor = row[column] / pivot[column];
+                for (value, pivot) in row.iter_mut().zip(&pivot).skip(column) {
+                    *value -= factor * pivot;
+                }
+            }
+        }
+    }
+
+    Some((0..size).map(|i| system[i][size] / system[i][i]).collect())
+}
+
+fn dot(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+/// How following a guide compares with playing at random against the opponent's shapes in it.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Evaluation {
+    /// How often the opponent plays each shape in the guide.
+    pub opponent: Mixed,
+    pub roshambo: f64,
+    pub fixed: f64,
+    pub uniform: f64,
+    pub equilibrium: f64,
+}
+
+impl Evaluation {
+    /// Evaluates the guide in scores per round, if it has any round.
+    pub fn new(guide: &str) -> Option<Self> {
+        let (roshambo, fixed) = compute_score::<(Tally<Roshambo>, Tally<FixedMatch>)>(guide);
+        let opponent = Mixed::new(roshambo.theirs.map(f64::from))?;
+        let rounds = f64::from(roshambo.rounds());
+        let equilibrium = equilibria()
+            .first()
+            .map_or(Mixed::UNIFORM, |equilibrium| equilibrium.ours);
+
+        Some(Self {
+            opponent,
+            roshambo: f64::from(roshambo.score) / rounds,
+            fixed: f64::from(fixed.score) / rounds,
+            uniform: expected_score(&Mixed::UNIFORM, &opponent),
+            equilibrium: expected_score(&equilibrium, &opponent),
+        })
+    }
+}
+
+impl Display for Evaluation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "The opponent plays {}.", self.opponent)?;
+        writeln!(f, "Expected score per round:")?;
+        for (name, score) in [
+            ("Roshambo", self.roshambo),
+            ("Fixed matches", self.fixed),
+            ("Uniformly random", self.uniform),
+            ("Equilibrium", self.equilibrium),
+        ] {
+            writeln!(f, "  {name:<18}{score:>6.3}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::generate::rand::{
+        rngs::StdRng,
+        SeedableRng,
+    };
+    use yare::parameterized;
+
+    use super::*;
+    use crate::Shape::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-6,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[parameterized(
+        zero        = { [0.0, 0.0, 0.0] },
+        negative    = { [1.0, -1.0, 1.0] },
+        nan         = { [f64::NAN, 1.0, 1.0] },
+    )]
+    fn new_fails(weights: [f64; 3]) {
+        assert_eq!(Mixed::new(weights), None);
+    }
+
+    #[test]
+    fn frequencies() {
+        let mixed = Mixed::frequencies([Rock, Rock, Scissors, Rock]).unwrap();
+
+        assert_eq!(mixed, Mixed::new([3.0, 0.0, 1.0]).unwrap());
+        assert_eq!(mixed.probability(Rock), 0.75);
+        assert_eq!(Mixed::frequencies([]), None);
+    }
+
+    #[parameterized(
+        draw    = { Rock,     Rock,     4.0 },
+        win     = { Paper,    Rock,     8.0 },
+        loss    = { Scissors, Rock,     3.0 },
+    )]
+    fn expected_pure(ours: Shape, theirs: Shape, expected: f64) {
+        let score = expected_score(&Mixed::pure(ours), &Mixed::pure(theirs));
+
+        assert_close(score, expected);
+    }
+
+    #[test]
+    fn expected_uniform() {
+        // Every shape scores 2 on average, and every outcome 3.
+        assert_close(expected_score(&Mixed::UNIFORM, &Mixed::UNIFORM), 5.0);
+    }
+
+    #[test]
+    fn simulation_converges() {
+        let ours = Mixed::new([1.0, 2.0, 3.0]).unwrap();
+        let theirs = Mixed::new([5.0, 1.0, 1.0]).unwrap();
+        let mut rng = StdRng::seed_from_u64(2022);
+
+        let simulated = simulate(&ours, &theirs, 100_000, &mut rng);
+
+        assert!((simulated - expected_score(&ours, &theirs)).abs() < 0.05);
+    }
+
+    #[test]
+    fn pure_samples() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        assert!((0..100).all(|_| Mixed::pure(Paper).sample(&mut rng) == Paper));
+    }
+
+    #[test]
+    fn single_equilibrium() {
+        let equilibria = equilibria();
+
+        assert_eq!(equilibria.len(), 1);
+        let equilibrium = equilibria[0];
+        for (mixed, score) in [
+            (equilibrium.ours, equilibrium.score),
+            (equilibrium.theirs, equilibrium.their_score),
+        ] {
+            assert_close(mixed.probability(Rock), 4.0 / 9.0);
+            assert_close(mixed.probability(Paper), 1.0 / 9.0);
+            assert_close(mixed.probability(Scissors), 4.0 / 9.0);
+            assert_close(score, 5.0);
+        }
+    }
+
+    #[test]
+    fn equilibrium_has_no_better_response() {
+        let equilibrium = equilibria()[0];
+
+        for shape in Shape::ALL {
+            let score = expected_score(&Mixed::pure(shape), &equilibrium.theirs);
+            assert!(score <= equilibrium.score + EPSILON);
+        }
+    }
+
+    #[test]
+    fn evaluate_example() {
+        const EXAMPLE: &str = include_str!("inputs/example.txt");
+
+        let evaluation = Evaluation::new(EXAMPLE).unwrap();
+
+        assert_eq!(evaluation.opponent, Mixed::UNIFORM);
+        assert_close(evaluation.roshambo, 5.0);
+        assert_close(evaluation.fixed, 4.0);
+        assert_close(evaluation.uniform, 5.0);
+        assert_close(evaluation.equilibrium, 5.0);
+        assert_eq!(Evaluation::new(""), None);
+    }
+}
@@ -0,0 +1,196 @@
+//! Best responses to the opponent's column of a guide, to measure how much the guide's own
+//! interpretations leave on the table.
+
+use std::{
+    fmt,
+    fmt::{
+        Display,
+        Formatter,
+    },
+};
+
+use crate::{
+    compute_score,
+    cyclic::{
+        Game,
+        Rps,
+        Variant,
+    },
+    split_line,
+    strategy::{
+        FixedMatch,
+        Roshambo,
+    },
+};
+
+/// The responses scoring the most against every round of a guide.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Optimum {
+    pub responses: Vec<usize>,
+    pub score: u32,
+}
+
+/// The shape scoring the most against `other`, the highest scoring shape among equals.
+pub fn best_response(game: Game, other: usize) -> usize {
+    (0..game.shapes())
+        .max_by_key(|&shape| game.score(shape) + game.outcome(shape, other).score())
+        .expect("a game has shapes")
+}
+
+/// Finds the best response to each round of the guide, reading only its opponent column and
+/// skipping the lines that are not two symbols of the game, like [`compute_score`] does.
+pub fn optimize<V>(guide: &str) -> Optimum
+where
+    V: Variant,
+{
+    let game = V::GAME;
+    let opponents: Vec<_> = guide
+        .lines()
+        .filter_map(split_line)
+        .filter(|&(_, response)| game.response(response).is_some())
+        .filter_map(|(letter, _)| game.opponent(letter))
+        .collect();
+
+    let responses: Vec<_> = opponents
+        .iter()
+        .map(|&other| best_response(game, other))
+        .collect();
+    let score = opponents
+        .iter()
+        .zip(&responses)
+        .map(|(&other, &shape)| game.score(shape) + game.outcome(shape, other).score())
+        .sum();
+
+    Optimum { responses, score }
+}
+
+/// The scores of the puzzle's interpretations of a guide next to the best achievable one.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Comparison {
+    pub optimum: u32,
+    pub roshambo: u32,
+    pub fixed: u32,
+}
+
+impl Comparison {
+    pub fn new(guide: &str) -> Self {
+        let (roshambo, fixed) = compute_score::<(Roshambo, FixedMatch)>(guide);
+        let optimum = optimize::<Rps>(guide).score;
+
+        Self { optimum, roshambo, fixed }
+    }
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let share = |score| {
+            if self.optimum == 0 {
+                100.0
+            } else {
+                f64::from(score) * 100.0 / f64::from(self.optimum)
+            }
+        };
+
+        for (name, score) in [
+            ("Best responses", self.optimum),
+            ("Roshambo", self.roshambo),
+            ("Fixed matches", self.fixed),
+        ] {
+            writeln!(f, "{name:<16}{score:>8}  {:>5.1}%", share(score))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use yare::parameterized;
+
+    use super::*;
+    use crate::cyclic::Rpsls;
+
+    const EXAMPLE: &str = include_str!("inputs/example.txt");
+
+    #[parameterized(
+        rock        = { 0, 1 },
+        paper       = { 1, 2 },
+        scissors    = { 2, 0 },
+    )]
+    fn best_rps_response(other: usize, expected: usize) {
+        assert_eq!(best_response(Game::RPS, other), expected);
+    }
+
+    #[test]
+    fn best_response_beats_every_other_response() {
+        for game in [Game::RPS, Game::RPSLS, Game::RPS7] {
+            for other in 0..game.shapes() {
+                let score = |shape| game.score(shape) + game.outcome(shape, other).score();
+                let best = best_response(game, other);
+
+                assert!((0..game.shapes()).all(|shape| score(shape) <= score(best)));
+            }
+        }
+    }
+
+    #[test]
+    fn example() {
+        let optimum = optimize::<Rps>(EXAMPLE);
+
+        assert_eq!(optimum.responses, [1, 2, 0]);
+        assert_eq!(optimum.score, 8 + 9 + 7);
+        assert_eq!(
+            Comparison::new(EXAMPLE),
+            Comparison {
+                optimum: 24,
+                roshambo: 15,
+                fixed: 12
+            }
+        );
+    }
+
+    #[test]
+    fn given_is_no_better_than_optimum() {
+        const INPUT: &str = include_str!("inputs/given.txt");
+
+        let comparison = Comparison::new(INPUT);
+
+        assert!(comparison.roshambo <= comparison.optimum);
+        assert!(comparison.fixed <= comparison.optimum);
+    }
+
+    #[test]
+    fn skips_other_shapes() {
+        let optimum = optimize::<Rps>("E X\n\nA Y\nA\nA Q\nAA Y\n");
+
+        assert_eq!(optimum.responses, [1]);
+        assert_eq!(optimize::<Rpsls>("E X").responses.len(), 1);
+    }
+
+    #[test]
+    fn compares_the_same_rounds() {
+        let guide = "A Y\nB\nC Q\nC Z\n";
+
+        let comparison = Comparison::new(guide);
+
+        assert_eq!(comparison.roshambo, 8 + 6);
+        assert_eq!(comparison.optimum, 8 + 7);
+    }
+
+    #[test]
+    fn display() {
+        let comparison = Comparison {
+            optimum: 24,
+            roshambo: 15,
+            fixed: 12,
+        };
+
+        assert_eq!(
+            comparison.to_string(),
+            "\
+Best responses        24  100.0%
+Roshambo              15   62.5%
+Fixed matches         12   50.0%
+"
+        );
+    }
+}
@@ -0,0 +1,183 @@
+//! Strategies known by name, to pick the ones to score a guide with at runtime.
+
+use snafu::prelude::*;
+
+use crate::{
+    cyclic::{
+        Rps7,
+        Rpsls,
+    },
+    strategy::{
+        Cyclic,
+        CyclicFixed,
+        DynStrategy,
+        Erased,
+        FixedMatch,
+        Roshambo,
+    },
+};
+
+#[non_exhaustive]
+#[derive(Debug, Snafu)]
+#[snafu(module(error), context(suffix(false)))]
+pub enum Error {
+    #[snafu(display("unknown strategy '{name}', expected one of: {}", known.join(", ")))]
+    UnknownStrategy {
+        name: String,
+        known: Vec<&'static str>,
+    },
+}
+
+/// Strategies by name, in the order they were registered.
+pub struct Registry {
+    strategies: Vec<(&'static str, Box<dyn DynStrategy>)>,
+}
+
+impl Registry {
+    /// A registry without any strategy.
+    pub fn empty() -> Self {
+        Self { strategies: Vec::new() }
+    }
+
+    /// Registers the strategy, replacing any other one of the same name.
+    pub fn register<S>(&mut self, name: &'static str, strategy: S) -> &mut Self
+    where
+        S: DynStrategy + 'static,
+    {
+        let strategy = Box::new(strategy);
+        match self.strategies.iter_mut().find(|(known, _)| *known == name) {
+            Some((_, known)) => *known = strategy,
+            None => self.strategies.push((name, strategy)),
+        }
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn DynStrategy> {
+        self.strategies
+            .iter()
+            .find(|(known, _)| *known == name)
+            .map(|(_, strategy)| strategy.as_ref())
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.strategies.iter().map(|&(name, _)| name)
+    }
+
+    /// Looks up every strategy of the list, failing on the first unknown name.
+    pub fn select<S>(&self, names: &[S]) -> Result<Vec<&dyn DynStrategy>, Error>
+    where
+        S: AsRef<str>,
+    {
+        names
+            .iter()
+            .map(|name| {
+                let name = name.as_ref();
+                self.get(name).context(error::UnknownStrategy {
+                    name,
+                    known: self.names().collect::<Vec<_>>(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// The puzzle's strategies and their counterparts for the other cyclic games.
+impl Default for Registry {
+    fn default() -> Self {
+        let mut registry = Self::empty();
+        registry
+            .register("roshambo", Erased::<Roshambo>::default())
+            .register("fixed", Erased::<FixedMatch>::default())
+            .register("rpsls", Erased::<Cyclic<Rpsls>>::default())
+            .register("rpsls-fixed", Erased::<CyclicFixed<Rpsls>>::default())
+            .register("rps7", Erased::<Cyclic<Rps7>>::default())
+            .register("rps7-fixed", Erased::<CyclicFixed<Rps7>>::default());
+        registry
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        compute_score,
+        compute_scores,
+    };
+
+    const EXAMPLE: &str = include_str!("inputs/example.txt");
+
+    #[test]
+    fn scores_selection_in_order() {
+        let registry = Registry::default();
+        let strategies = registry.select(&["fixed", "roshambo", "fixed"]).unwrap();
+
+        let scores = compute_scores(EXAMPLE, &strategies);
+
+        assert_eq!(scores, [12, 15, 12]);
+    }
+
+    #[test]
+    fn matches_static_strategies() {
+        const INPUT: &str = include_str!("inputs/given.txt");
+        let registry = Registry::default();
+        let names: Vec<_> = registry.names().collect();
+
+        let scores = compute_scores(INPUT, &registry.select(&names).unwrap());
+
+        let (roshambo, fixed) = compute_score::<(Roshambo, FixedMatch)>(INPUT);
+        let (rpsls, rpsls_fixed) = compute_score::<(Cyclic<Rpsls>, CyclicFixed<Rpsls>)>(INPUT);
+        let (rps7, rps7_fixed) = compute_score::<(Cyclic<Rps7>, CyclicFixed<Rps7>)>(INPUT);
+        assert_eq!(
+            scores,
+            [roshambo, fixed, rpsls, rpsls_fixed, rps7, rps7_fixed]
+        );
+    }
+
+    #[test]
+    fn strategies_read_their_own_lines() {
+        let registry = Registry::default();
+        let strategies = registry.select(&["roshambo", "rpsls"]).unwrap();
+
+        // Lizard and Spock are unknown to the puzzle's game.
+        let scores = compute_scores("A V\nE Z\nA X", &strategies);
+
+        assert_eq!(scores, [4, (1 + 3) + (5 + 3) + 3]);
+    }
+
+    #[test]
+    fn skips_short_lines() {
+        let registry = Registry::default();
+        let strategies = registry.select(&["roshambo"]).unwrap();
+
+        let scores = compute_scores("A Y\n\nB\nC Z\n", &strategies);
+
+        assert_eq!(scores, [8 + 6]);
+    }
+
+    #[test]
+    fn register_replaces() {
+        let mut registry = Registry::default();
+        registry.register("roshambo", Erased::<FixedMatch>::default());
+
+        let scores = compute_scores(EXAMPLE, &registry.select(&["roshambo"]).unwrap());
+
+        assert_eq!(scores, [12]);
+        assert_eq!(
+            registry.names().filter(|&name| name == "roshambo").count(),
+            1
+        );
+    }
+
+    #[test]
+    fn unknown_strategy() {
+        let registry = Registry::default();
+
+        let error = registry.select(&["roshambo", "uwu"]).err().unwrap();
+
+        assert_eq!(
+            error.to_string(),
+            "unknown strategy 'uwu', expected one of: roshambo, fixed, rpsls, rpsls-fixed, rps7, \
+             rps7-fixed"
+        );
+    }
+}
@@ -0,0 +1,286 @@
+//! Strategies reporting how a score was reached, round by round or as a whole.
+
+use std::{
+    fmt,
+    fmt::{
+        Display,
+        Formatter,
+    },
+    marker::PhantomData,
+};
+
+use crate::{
+    strategy::{
+        Resolve,
+        Round,
+        Strategy,
+    },
+    Error,
+    Outcome,
+    Shape,
+};
+
+/// Outcomes and shapes played over a whole guide.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Stats {
+    pub wins: u32,
+    pub draws: u32,
+    pub losses: u32,
+    /// Times we played each shape, indexed by [`Shape`].
+    pub ours: [u32; 3],
+    /// Times the opponent played each shape, indexed by [`Shape`].
+    pub theirs: [u32; 3],
+    pub score: u32,
+}
+
+impl Stats {
+    pub fn rounds(&self) -> u32 {
+        self.wins + self.draws + self.losses
+    }
+
+    pub(crate) fn merge(mut self, other: Self) -> Self {
+        self.wins += other.wins;
+        self.draws += other.draws;
+        self.losses += other.losses;
+        for shape in 0..Shape::ALL.len() {
+            self.ours[shape] += other.ours[shape];
+            self.theirs[shape] += other.theirs[shape];
+        }
+        self.score += other.score;
+        self
+    }
+}
+
+impl From<Round> for Stats {
+    fn from(round: Round) -> Self {
+        let mut stats = Self {
+            score: round.score(),
+            ..Self::default()
+        };
+        match round.outcome {
+            Outcome::Win => stats.wins = 1,
+            Outcome::Draw => stats.draws = 1,
+            Outcome::Lose => stats.losses = 1,
+        }
+        stats.ours[round.ours as usize] = 1;
+        stats.theirs[round.theirs as usize] = 1;
+        stats
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} rounds: {} wins, {} draws, {} losses, scoring {}",
+            self.rounds(),
+            self.wins,
+            self.draws,
+            self.losses,
+            self.score
+        )?;
+        writeln!(f, "{:<10}{:>8}{:>8}", "Shape", "Ours", "Theirs")?;
+        for (i, shape) in Shape::ALL.iter().enumerate() {
+            let name = format!("{shape:?}");
+            writeln!(f, "{name:<10}{:>8}{:>8}", self.ours[i], self.theirs[i])?;
+        }
+        Ok(())
+    }
+}
+
+/// Scores a guide like `S`, tallying its rounds into [`Stats`].
+pub struct Tally<S>(PhantomData<S>);
+
+impl<S> Strategy for Tally<S>
+where
+    S: Resolve,
+{
+    type Input = S::Input;
+    type Out = Stats;
+
+    fn parse_match(line: (u8, u8)) -> Result<Self::Input, Error> {
+        S::parse_match(line)
+    }
+
+    fn match_score(input: Self::Input) -> Self::Out {
+        S::resolve(&input).into()
+    }
+
+    fn reduce(a: Self::Out, b: Self::Out) -> Self::Out {
+        a.merge(b)
+    }
+}
+
+/// A round of a guide, with the score so far.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Step {
+    pub round: Round,
+    pub total: u32,
+}
+
+/// Every round of a guide, in order.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Transcript {
+    pub steps: Vec<Step>,
+}
+
+impl Transcript {
+    pub fn total(&self) -> u32 {
+        self.steps.last().map_or(0, |step| step.total)
+    }
+}
+
+impl Display for Transcript {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>6}  {:<9}{:<9}{:<6}{:>6}{:>8}",
+            "Round", "Theirs", "Ours", "Result", "Score", "Total"
+        )?;
+        for (i, Step { round, total }) in self.steps.iter().enumerate() {
+            let (theirs, ours, outcome) = (
+                format!("{:?}", round.theirs),
+                format!("{:?}", round.ours),
+                format!("{:?}", round.outcome),
+            );
+            writeln!(
+                f,
+                "{:>6}  {theirs:<9}{ours:<9}{outcome:<6}{:>6}{total:>8}",
+                i + 1,
+                round.score()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Scores a guide like `S`, keeping every round in a [`Transcript`].
+pub struct Trace<S>(PhantomData<S>);
+
+impl<S> Strategy for Trace<S>
+where
+    S: Resolve,
+{
+    type Input = S::Input;
+    type Out = Transcript;
+
+    fn parse_match(line: (u8, u8)) -> Result<Self::Input, Error> {
+        S::parse_match(line)
+    }
+
+    fn match_score(input: Self::Input) -> Self::Out {
+        let round = S::resolve(&input);
+        Transcript {
+            steps: vec![Step { round, total: round.score() }],
+        }
+    }
+
+    fn reduce(mut a: Self::Out, b: Self::Out) -> Self::Out {
+        let before = a.total();
+        a.steps.extend(
+            b.steps
+                .into_iter()
+                .map(|step| Step { total: before + step.total, ..step }),
+        );
+        a
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        compute_score,
+        strategy::{
+            FixedMatch,
+            Roshambo,
+        },
+        Outcome::*,
+        Shape::*,
+    };
+
+    const EXAMPLE: &str = include_str!("inputs/example.txt");
+
+    #[test]
+    fn tally_example() {
+        let (roshambo, fixed) = compute_score::<(Tally<Roshambo>, Tally<FixedMatch>)>(EXAMPLE);
+
+        assert_eq!(
+            roshambo,
+            Stats {
+                wins: 1,
+                draws: 1,
+                losses: 1,
+                ours: [1, 1, 1],
+                theirs: [1, 1, 1],
+                score: 15,
+            }
+        );
+        assert_eq!(
+            fixed,
+            Stats {
+                wins: 1,
+                draws: 1,
+                losses: 1,
+                ours: [3, 0, 0],
+                theirs: [1, 1, 1],
+                score: 12,
+            }
+        );
+    }
+
+    #[test]
+    fn tally_matches_score() {
+        const INPUT: &str = include_str!("inputs/given.txt");
+
+        let (stats, score) = compute_score::<(Tally<Roshambo>, Roshambo)>(INPUT);
+
+        assert_eq!(stats.score, score);
+        assert_eq!(stats.rounds() as usize, INPUT.lines().count());
+        assert_eq!(stats.ours.iter().sum::<u32>(), stats.rounds());
+    }
+
+    #[test]
+    fn trace_example() {
+        let transcript = compute_score::<Trace<FixedMatch>>(EXAMPLE);
+
+        let step = |ours, theirs, outcome, total| Step {
+            round: Round { ours, theirs, outcome },
+            total,
+        };
+        assert_eq!(
+            transcript.steps,
+            [
+                step(Rock, Rock, Draw, 4),
+                step(Rock, Paper, Lose, 5),
+                step(Rock, Scissors, Win, 12),
+            ]
+        );
+        assert_eq!(transcript.total(), 12);
+    }
+
+    #[test]
+    fn display() {
+        let (stats, transcript) = compute_score::<(Tally<Roshambo>, Trace<Roshambo>)>(EXAMPLE);
+
+        assert_eq!(
+            stats.to_string(),
+            "\
+3 rounds: 1 wins, 1 draws, 1 losses, scoring 15
+Shape         Ours  Theirs
+Rock             1       1
+Paper            1       1
+Scissors         1       1
+"
+        );
+        assert_eq!(
+            transcript.to_string(),
+            "\
+\x20Round  Theirs   Ours     Result Score   Total
+     1  Rock     Paper    Win        8       8
+     2  Paper    Rock     Lose       1       9
+     3  Scissors Scissors Draw       6      15
+"
+        );
+    }
+}
@@ -1,4 +1,11 @@
+use std::marker::PhantomData;
+
+use snafu::prelude::*;
+
 use crate::{
+    cyclic::Variant,
+    error,
+    Error,
     Outcome,
     Outcome::{
         Draw,
@@ -17,38 +24,64 @@ pub trait Strategy {
     type Input;
     type Out: Default;
 
-    fn parse_match(line: (u8, u8)) -> Option<Self::Input>;
+    /// Reads the symbols of a line of the guide.
+    fn parse_match(line: (u8, u8)) -> Result<Self::Input, Error>;
 
     fn match_score(input: Self::Input) -> Self::Out;
 
     fn reduce(a: Self::Out, b: Self::Out) -> Self::Out;
 }
 
+/// What was played in a round, and how it ended for us.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Round {
+    pub ours: Shape,
+    pub theirs: Shape,
+    pub outcome: Outcome,
+}
+
+impl Round {
+    pub fn score(&self) -> u32 {
+        self.ours.score() + self.outcome.score()
+    }
+}
+
+/// A strategy whose rounds can be told apart, to report more than their score.
+pub trait Resolve: Strategy {
+    fn resolve(input: &Self::Input) -> Round;
+}
+
 pub struct Roshambo;
 
 impl Strategy for Roshambo {
     type Input = (Shape, Shape);
     type Out = u32;
 
-    fn parse_match((this, other): (u8, u8)) -> Option<Self::Input> {
-        let self_play = Shape::try_from(other).ok()?;
-        let other_play = Shape::try_from(this).ok()?;
+    fn parse_match((this, other): (u8, u8)) -> Result<Self::Input, Error> {
+        let other_play = Shape::try_from(this)?;
+        let self_play = Shape::try_from(other)?;
 
-        Some((self_play, other_play))
+        Ok((self_play, other_play))
     }
 
-    fn match_score((self_play, other_play): Self::Input) -> Self::Out {
-        let result = match (self_play, other_play) {
+    fn match_score(input: Self::Input) -> Self::Out {
+        Self::resolve(&input).score()
+    }
+
+    fn reduce(a: Self::Out, b: Self::Out) -> Self::Out {
+        a + b
+    }
+}
+
+impl Resolve for Roshambo {
+    fn resolve(&(ours, theirs): &Self::Input) -> Round {
+        let outcome = match (ours, theirs) {
             (Paper, Rock) | (Scissors, Paper) | (Rock, Scissors) => Win,
             (a, b) if a == b => Draw,
             _ => Lose,
         };
 
-        self_play.score() + result.score()
-    }
-
-    fn reduce(a: Self::Out, b: Self::Out) -> Self::Out {
-        a + b
+        Round { ours, theirs, outcome }
     }
 }
 
@@ -58,11 +91,11 @@ impl Strategy for FixedMatch {
     type Input = (Outcome, Shape);
     type Out = u32;
 
-    fn parse_match((this, other): (u8, u8)) -> Option<Self::Input> {
-        let result = Outcome::try_from(other).ok()?;
-        let other_play = Shape::try_from(this).ok()?;
+    fn parse_match((this, other): (u8, u8)) -> Result<Self::Input, Error> {
+        let other_play = Shape::try_from(this)?;
+        let result = Outcome::try_from(other)?;
 
-        Some((result, other_play))
+        Ok((result, other_play))
     }
 
     fn match_score((result, other_play): Self::Input) -> Self::Out {
@@ -75,6 +108,114 @@ impl Strategy for FixedMatch {
     }
 }
 
+impl Resolve for FixedMatch {
+    fn resolve(&(outcome, theirs): &Self::Input) -> Round {
+        Round {
+            ours: theirs.solve_for(outcome),
+            theirs,
+            outcome,
+        }
+    }
+}
+
+/// Like [`Roshambo`], for any cyclic game.
+pub struct Cyclic<V>(PhantomData<V>);
+
+impl<V> Strategy for Cyclic<V>
+where
+    V: Variant,
+{
+    type Input = (usize, usize);
+    type Out = u32;
+
+    fn parse_match((this, other): (u8, u8)) -> Result<Self::Input, Error> {
+        let other_play = V::GAME
+            .opponent(this)
+            .context(error::UnknownSymbol { byte: this, expected: "a shape" })?;
+        let self_play = V::GAME
+            .response(other)
+            .context(error::UnknownSymbol { byte: other, expected: "a shape" })?;
+
+        Ok((self_play, other_play))
+    }
+
+    fn match_score((self_play, other_play): Self::Input) -> Self::Out {
+        let result = V::GAME.outcome(self_play, other_play);
+        V::GAME.score(self_play) + result.score()
+    }
+
+    fn reduce(a: Self::Out, b: Self::Out) -> Self::Out {
+        a + b
+    }
+}
+
+/// Like [`FixedMatch`], for any cyclic game.
+pub struct CyclicFixed<V>(PhantomData<V>);
+
+impl<V> Strategy for CyclicFixed<V>
+where
+    V: Variant,
+{
+    type Input = (Outcome, usize);
+    type Out = u32;
+
+    fn parse_match((this, other): (u8, u8)) -> Result<Self::Input, Error> {
+        let other_play = V::GAME
+            .opponent(this)
+            .context(error::UnknownSymbol { byte: this, expected: "a shape" })?;
+        let result = Outcome::try_from(other)?;
+
+        Ok((result, other_play))
+    }
+
+    fn match_score((result, other_play): Self::Input) -> Self::Out {
+        let self_play = V::GAME.solve_for(other_play, result);
+        V::GAME.score(self_play) + result.score()
+    }
+
+    fn reduce(a: Self::Out, b: Self::Out) -> Self::Out {
+        a + b
+    }
+}
+
+/// Object-safe counterpart of [`Strategy`] for strategies scoring with a number, so that they can
+/// be picked at runtime.
+pub trait DynStrategy {
+    /// Scores a line of the guide, or returns `None` if the strategy cannot read it.
+    fn line_score(&self, line: (u8, u8)) -> Option<u32>;
+
+    /// Checks that the strategy can read a line of the guide.
+    fn check(&self, line: (u8, u8)) -> Result<(), Error>;
+
+    fn reduce(&self, a: u32, b: u32) -> u32;
+}
+
+/// A [`Strategy`] behind a [`DynStrategy`].
+pub struct Erased<S>(PhantomData<S>);
+
+impl<S> Default for Erased<S> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<S> DynStrategy for Erased<S>
+where
+    S: Strategy<Out = u32>,
+{
+    fn line_score(&self, line: (u8, u8)) -> Option<u32> {
+        S::parse_match(line).ok().map(S::match_score)
+    }
+
+    fn check(&self, line: (u8, u8)) -> Result<(), Error> {
+        S::parse_match(line).map(drop)
+    }
+
+    fn reduce(&self, a: u32, b: u32) -> u32 {
+        S::reduce(a, b)
+    }
+}
+
 impl<A, B> Strategy for (A, B)
 where
     A: Strategy,
@@ -83,8 +224,8 @@ where
     type Input = (A::Input, B::Input);
     type Out = (A::Out, B::Out);
 
-    fn parse_match(line: (u8, u8)) -> Option<Self::Input> {
-        Some((A::parse_match(line)?, B::parse_match(line)?))
+    fn parse_match(line: (u8, u8)) -> Result<Self::Input, Error> {
+        Ok((A::parse_match(line)?, B::parse_match(line)?))
     }
 
     fn match_score((a, b): Self::Input) -> Self::Out {
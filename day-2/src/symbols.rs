@@ -0,0 +1,318 @@
+//! Symbols a strategy guide uses for each column, so that guides written with other letters or whole
+//! words score like the puzzle's own.
+//!
+//! A symbol table lists, for each column, the symbols standing for the puzzle's `A B C` and `X Y Z`,
+//! in that order:
+//!
+//! ```text
+//! # column    first   second  third
+//! opponent    rock    paper   scissors
+//! response    rock    paper   scissors
+//! ```
+//!
+//! Symbols are matched regardless of their case, and lines starting with `#` are comments.
+
+use std::{
+    borrow::Cow,
+    fmt::Write,
+    str::FromStr,
+};
+
+use snafu::prelude::*;
+
+use crate::Parsing;
+
+/// Letters of the puzzle for the opponent column.
+pub const OPPONENT: [&str; 3] = ["A", "B", "C"];
+/// Letters of the puzzle for the response column.
+pub const RESPONSE: [&str; 3] = ["X", "Y", "Z"];
+
+#[non_exhaustive]
+#[derive(Debug, Snafu)]
+#[snafu(module(error), context(suffix(false)))]
+pub enum Error {
+    #[snafu(display("invalid symbol table entry at line {line}: '{entry}'"))]
+    InvalidEntry { line: usize, entry: String },
+    #[snafu(display("the {column} column needs 3 distinct symbols, got {symbols:?}"))]
+    InvalidSymbols {
+        column: &'static str,
+        symbols: Vec<String>,
+    },
+    #[snafu(display("line {line} of the guide is not two known symbols: '{content}'"))]
+    UnknownSymbol { line: usize, content: String },
+}
+
+/// The symbols of both columns of a guide, in the order of the puzzle's letters.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Symbols {
+    opponent: [String; 3],
+    response: [String; 3],
+}
+
+impl Default for Symbols {
+    fn default() -> Self {
+        Self {
+            opponent: OPPONENT.map(String::from),
+            response: RESPONSE.map(String::from),
+        }
+    }
+}
+
+impl Symbols {
+    pub fn new<S>(opponent: &[S], response: &[S]) -> Result<Self, Error>
+    where
+        S: AsRef<str>,
+    {
+        Ok(Self {
+            opponent: column("opponent", opponent)?,
+            response: column("response", response)?,
+        })
+    }
+
+    /// Whether these are the puzzle's letters, which guides need no translation for.
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Rewrites the guide with the puzzle's letters, keeping blank lines so that the lines keep
+    /// their numbers. Lines that are not two known symbols fail the translation in strict parsing,
+    /// and are left blank and reported in lenient parsing.
+    ///
+    /// With the puzzle's own letters, the guide is only uppercased, leaving its lines for the
+    /// strategies to read since those of other games use more letters.
+    pub fn translate<'g>(
+        &self,
+        guide: &'g str,
+        parsing: Parsing,
+    ) -> Result<Translation<'g>, Error> {
+        if self.is_default() {
+            let guide = if guide.bytes().any(|byte| byte.is_ascii_lowercase()) {
+                Cow::Owned(guide.to_ascii_uppercase())
+            } else {
+                Cow::Borrowed(guide)
+            };
+            return Ok(Translation { guide, skipped: Vec::new() });
+        }
+
+        let mut translated = String::with_capacity(guide.len());
+        let mut skipped = Vec::new();
+        for (content, line) in guide.lines().zip(1_usize..) {
+            if content.trim().is_empty() {
+                translated.push('\n');
+                continue;
+            }
+
+            match self.translate_line(content) {
+                Some((opponent, response)) => {
+                    writeln!(translated, "{} {}", OPPONENT[opponent], RESPONSE[response]).unwrap();
+                }
+                None => {
+                    let error = error::UnknownSymbol { line, content }.build();
+                    if parsing == Parsing::Strict {
+                        return Err(error);
+                    }
+                    skipped.push(error);
+                    translated.push('\n');
+                }
+            }
+        }
+
+        Ok(Translation {
+            guide: Cow::Owned(translated),
+            skipped,
+        })
+    }
+
+    fn translate_line(&self, content: &str) -> Option<(usize, usize)> {
+        let mut symbols = content.split_whitespace();
+        let opponent = position(&self.opponent, symbols.next()?)?;
+        let response = position(&self.response, symbols.next()?)?;
+        symbols.next().is_none().then_some((opponent, response))
+    }
+}
+
+/// A guide rewritten with the puzzle's letters, with the lines skipped to reach it.
+#[derive(Debug)]
+pub struct Translation<'g> {
+    pub guide: Cow<'g, str>,
+    pub skipped: Vec<Error>,
+}
+
+impl FromStr for Symbols {
+    type Err = Error;
+
+    fn from_str(table: &str) -> Result<Self, Self::Err> {
+        let mut symbols = Self::default();
+
+        for (entry, line) in table.lines().zip(1_usize..) {
+            let entry = entry.trim();
+            if entry.is_empty() || entry.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || error::InvalidEntry { line, entry }.build();
+            let (name, rest) = entry.split_once(char::is_whitespace).ok_or_else(invalid)?;
+            let values: Vec<_> = rest.split_whitespace().collect();
+            match name {
+                "opponent" => symbols.opponent = column("opponent", &values)?,
+                "response" => symbols.response = column("response", &values)?,
+                _ => return Err(invalid()),
+            }
+        }
+
+        Ok(symbols)
+    }
+}
+
+fn column<S>(name: &'static str, symbols: &[S]) -> Result<[String; 3], Error>
+where
+    S: AsRef<str>,
+{
+    let owned: Vec<_> = symbols.iter().map(|s| s.as_ref().to_owned()).collect();
+    let invalid = || {
+        error::InvalidSymbols {
+            column: name,
+            symbols: owned.clone(),
+        }
+        .build()
+    };
+
+    let column: [String; 3] = owned.clone().try_into().map_err(|_| invalid())?;
+    let valid = column.iter().enumerate().all(|(i, symbol)| {
+        !symbol.is_empty()
+            && !symbol.contains(char::is_whitespace)
+            && column[..i]
+                .iter()
+                .all(|other| !other.eq_ignore_ascii_case(symbol))
+    });
+    ensure!(
+        valid,
+        error::InvalidSymbols { column: name, symbols: owned }
+    );
+
+    Ok(column)
+}
+
+fn position(column: &[String; 3], symbol: &str) -> Option<usize> {
+    column
+        .iter()
+        .position(|known| known.eq_ignore_ascii_case(symbol))
+}
+
+#[cfg(test)]
+mod test {
+    use yare::parameterized;
+
+    use super::*;
+
+    const WORDS: &str = "\
+        # column    first   second  third
+        opponent    rock    paper   scissors
+        response    rock    paper   scissors
+    ";
+
+    #[test]
+    fn parse_table() {
+        let symbols: Symbols = WORDS.parse().unwrap();
+
+        assert_eq!(
+            symbols,
+            Symbols::new(
+                &["rock", "paper", "scissors"],
+                &["rock", "paper", "scissors"]
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_partial_table() {
+        let symbols: Symbols = "response 1 2 3".parse().unwrap();
+
+        assert_eq!(symbols, Symbols::new(&OPPONENT, &["1", "2", "3"]).unwrap());
+    }
+
+    #[parameterized(
+        unknown_column  = { "player a b c" },
+        missing_symbols = { "opponent" },
+        too_few         = { "opponent a b" },
+        too_many        = { "opponent a b c d" },
+        duplicates      = { "response a b A" },
+    )]
+    fn parse_table_fails(table: &str) {
+        assert!(table.parse::<Symbols>().is_err());
+    }
+
+    #[test]
+    fn translate_words() {
+        let symbols: Symbols = WORDS.parse().unwrap();
+
+        let translation = symbols
+            .translate(
+                "rock paper\n\nPaper  rock\r\nscissors scissors",
+                Parsing::Strict,
+            )
+            .unwrap();
+
+        assert_eq!(translation.guide, "A Y\n\nB X\nC Z\n");
+        assert!(translation.skipped.is_empty());
+    }
+
+    #[test]
+    fn translate_default_borrows() {
+        let translation = Symbols::default()
+            .translate("A Y", Parsing::Strict)
+            .unwrap();
+
+        assert!(matches!(translation.guide, Cow::Borrowed("A Y")));
+    }
+
+    #[test]
+    fn translate_default_uppercases() {
+        let translation = Symbols::default()
+            .translate("a y\n\nB x\r\nc Z", Parsing::Strict)
+            .unwrap();
+
+        assert_eq!(translation.guide, "A Y\n\nB X\r\nC Z");
+        assert!(translation.skipped.is_empty());
+    }
+
+    #[parameterized(
+        unknown_opponent    = { "lizard rock" },
+        unknown_response    = { "rock spock" },
+        missing_response    = { "rock" },
+        extra_symbol        = { "rock paper scissors" },
+    )]
+    fn translate_fails(line: &str) {
+        let symbols: Symbols = WORDS.parse().unwrap();
+
+        let error = symbols
+            .translate(&format!("rock paper\n{line}"), Parsing::Strict)
+            .unwrap_err();
+
+        assert!(matches!(error, Error::UnknownSymbol { line: 2, .. }));
+    }
+
+    #[test]
+    fn translate_lenient() {
+        let symbols: Symbols = WORDS.parse().unwrap();
+
+        let translation = symbols
+            .translate(
+                "rock paper\n\nlizard rock\nrock\nscissors rock\n",
+                Parsing::Lenient,
+            )
+            .unwrap();
+
+        assert_eq!(translation.guide, "A Y\n\n\n\nC X\n");
+        let lines: Vec<_> = translation
+            .skipped
+            .iter()
+            .map(|error| match error {
+                Error::UnknownSymbol { line, .. } => *line,
+                _ => panic!("expected an unknown symbol"),
+            })
+            .collect();
+        assert_eq!(lines, [3, 4]);
+    }
+}
@@ -0,0 +1,304 @@
+//! Round-robin tournaments between players following their own strategy guides.
+//!
+//! Every player plays every other one, round by round through their guides, for as many rounds as
+//! both guides have.
+
+use std::{
+    cmp::Reverse,
+    fmt,
+    fmt::{
+        Display,
+        Formatter,
+    },
+};
+
+use crate::{
+    score_guide,
+    stats::Trace,
+    strategy::{
+        Resolve,
+        Roshambo,
+    },
+    Error,
+    Outcome,
+    Parsing,
+    Scored,
+    Shape,
+};
+
+/// A player of the tournament, with the shapes they play in order.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Player {
+    pub name: String,
+    pub shapes: Vec<Shape>,
+}
+
+impl Player {
+    pub fn new(name: impl Into<String>, shapes: Vec<Shape>) -> Self {
+        Self { name: name.into(), shapes }
+    }
+
+    /// A player playing the shapes the guide tells them to when read with the strategy, with the
+    /// lines of the guide skipped in lenient parsing.
+    pub fn from_guide<S>(
+        name: impl Into<String>,
+        guide: &str,
+        parsing: Parsing,
+    ) -> Result<(Self, Vec<Error>), Error>
+    where
+        S: Resolve,
+    {
+        let Scored { score: transcript, skipped } = score_guide::<Trace<S>>(guide, parsing)?;
+        let shapes = transcript
+            .steps
+            .iter()
+            .map(|step| step.round.ours)
+            .collect();
+
+        Ok((Self::new(name, shapes), skipped))
+    }
+}
+
+/// Results of two players against each other, ordered like the players.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Pairing {
+    pub players: [usize; 2],
+    pub scores: [u32; 2],
+    pub wins: [u32; 2],
+    pub draws: u32,
+}
+
+impl Pairing {
+    fn play(players: [usize; 2], first: &[Shape], second: &[Shape]) -> Self {
+        let mut pairing = Self { players, ..Self::default() };
+
+        for (&a, &b) in first.iter().zip(second) {
+            let first = Roshambo::resolve(&(a, b));
+            let second = Roshambo::resolve(&(b, a));
+            pairing.scores[0] += first.score();
+            pairing.scores[1] += second.score();
+            match first.outcome {
+                Outcome::Win => pairing.wins[0] += 1,
+                Outcome::Lose => pairing.wins[1] += 1,
+                Outcome::Draw => pairing.draws += 1,
+            }
+        }
+
+        pairing
+    }
+}
+
+/// Totals of a player over the whole tournament.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Standing {
+    pub player: usize,
+    pub score: u32,
+    pub wins: u32,
+    pub draws: u32,
+    pub losses: u32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tournament {
+    pub players: Vec<Player>,
+    /// Every pair of players, the earlier one first.
+    pub pairings: Vec<Pairing>,
+}
+
+impl Tournament {
+    pub fn play(players: Vec<Player>) -> Self {
+        let pairings = (0..players.len())
+            .flat_map(|a| (a + 1..players.len()).map(move |b| [a, b]))
+            .map(|[a, b]| Pairing::play([a, b], &players[a].shapes, &players[b].shapes))
+            .collect();
+
+        Self { players, pairings }
+    }
+
+    /// The totals of every player, the highest score first, then the most wins.
+    pub fn standings(&self) -> Vec<Standing> {
+        let mut standings: Vec<_> = (0..self.players.len())
+            .map(|player| Standing { player, ..Standing::default() })
+            .collect();
+
+        for pairing in &self.pairings {
+            for side in 0..2 {
+                let standing = &mut standings[pairing.players[side]];
+                standing.score += pairing.scores[side];
+                standing.wins += pairing.wins[side];
+                standing.draws += pairing.draws;
+                standing.losses += pairing.wins[1 - side];
+            }
+        }
+
+        standings.sort_by_key(|standing| Reverse((standing.score, standing.wins)));
+        standings
+    }
+}
+
+impl Display for Tournament {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name = |player: usize| self.players[player].name.as_str();
+        let width = self
+            .players
+            .iter()
+            .map(|player| player.name.len())
+            .max()
+            .unwrap_or(0)
+            .max("Player".len());
+
+        writeln!(
+            f,
+            "Rank  {:<width$}  {:>8}  {:>6}  {:>6}  {:>6}",
+            "Player", "Score", "Wins", "Draws", "Losses"
+        )?;
+        for (rank, standing) in self.standings().iter().enumerate() {
+            writeln!(
+                f,
+                "{:>4}  {:<width$}  {:>8}  {:>6}  {:>6}  {:>6}",
+                rank + 1,
+                name(standing.player),
+                standing.score,
+                standing.wins,
+                standing.draws,
+                standing.losses
+            )?;
+        }
+
+        writeln!(f)?;
+        for pairing in &self.pairings {
+            let [a, b] = pairing.players;
+            let [score_a, score_b] = pairing.scores;
+            writeln!(
+                f,
+                "{:>width$} {score_a:>8} - {score_b:<8} {}",
+                name(a),
+                name(b)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        strategy::FixedMatch,
+        Shape::*,
+    };
+
+    fn players() -> Vec<Player> {
+        vec![
+            Player::new("rocky", vec![Rock, Rock, Rock]),
+            Player::new("cycle", vec![Rock, Paper, Scissors]),
+            Player::new("paper", vec![Paper, Paper]),
+        ]
+    }
+
+    #[test]
+    fn pairings() {
+        let tournament = Tournament::play(players());
+
+        assert_eq!(
+            tournament.pairings,
+            [
+                Pairing {
+                    players: [0, 1],
+                    scores: [4 + 1 + 7, 4 + 8 + 3],
+                    wins: [1, 1],
+                    draws: 1,
+                },
+                Pairing {
+                    players: [0, 2],
+                    scores: [1 + 1, 8 + 8],
+                    wins: [0, 2],
+                    draws: 0,
+                },
+                Pairing {
+                    players: [1, 2],
+                    scores: [1 + 5, 8 + 5],
+                    wins: [0, 1],
+                    draws: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn standings() {
+        let tournament = Tournament::play(players());
+
+        let standings = tournament.standings();
+
+        let ranked: Vec<_> = standings
+            .iter()
+            .map(|standing| (standing.player, standing.score))
+            .collect();
+        assert_eq!(ranked, [(2, 29), (1, 21), (0, 14)]);
+        assert_eq!(
+            standings[0],
+            Standing {
+                player: 2,
+                score: 29,
+                wins: 3,
+                draws: 1,
+                losses: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn from_guide() {
+        const EXAMPLE: &str = include_str!("inputs/example.txt");
+
+        let (roshambo, _) = Player::from_guide::<Roshambo>("a", EXAMPLE, Parsing::Strict).unwrap();
+        let (fixed, _) = Player::from_guide::<FixedMatch>("b", EXAMPLE, Parsing::Strict).unwrap();
+
+        assert_eq!(roshambo.shapes, [Paper, Rock, Scissors]);
+        assert_eq!(fixed.shapes, [Rock, Rock, Rock]);
+        assert!(Player::from_guide::<Roshambo>("c", "A Y\nB", Parsing::Strict).is_err());
+    }
+
+    #[test]
+    fn from_guide_reports_skipped() {
+        let (player, skipped) =
+            Player::from_guide::<Roshambo>("a", "A Y\nB\nC Q\nC Z", Parsing::Lenient).unwrap();
+
+        assert_eq!(player.shapes, [Paper, Scissors]);
+        assert!(matches!(
+            skipped[..],
+            [
+                Error::MalformedLine { line: 2, .. },
+                Error::InvalidLine { line: 3, .. }
+            ]
+        ));
+    }
+
+    #[test]
+    fn display() {
+        let tournament = Tournament::play(players());
+
+        assert_eq!(
+            tournament.to_string(),
+            "\
+Rank  Player     Score    Wins   Draws  Losses
+   1  paper         29       3       1       0
+   2  cycle         21       1       2       2
+   3  rocky         14       1       1       3
+
+ rocky       12 - 15       cycle
+ rocky        2 - 16       paper
+ cycle        6 - 13       paper
+"
+        );
+    }
+
+    #[test]
+    fn single_player() {
+        let tournament = Tournament::play(vec![Player::new("alone", vec![Rock])]);
+
+        assert!(tournament.pairings.is_empty());
+        assert_eq!(tournament.standings()[0].score, 0);
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_common::answers;
+use day_2::Day2;
+
+#[test]
+fn recorded_answers() {
+    answers::verify::<Day2>(concat!(env!("CARGO_MANIFEST_DIR"), "/src/inputs"));
+}
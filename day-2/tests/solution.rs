@@ -0,0 +1,60 @@
+use aoc_common::solution::Solution;
+use day_2::{
+    compute_score,
+    cyclic::{
+        Rps,
+        Rpsls,
+    },
+    strategy::{
+        Cyclic,
+        CyclicFixed,
+        FixedMatch,
+        Roshambo,
+    },
+    Day2,
+    Outcome,
+    Shape,
+};
+
+const EXAMPLE: &str = include_str!("../src/inputs/example.txt");
+
+#[test]
+fn example_both_strategies() {
+    let scores = compute_score::<(Roshambo, FixedMatch)>(EXAMPLE);
+
+    assert_eq!(scores, (15, 12));
+}
+
+#[test]
+fn example_solution() {
+    let guide = Day2::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day2::part_1(&guide).unwrap(), 15);
+    assert_eq!(Day2::part_2(&guide).unwrap(), 12);
+}
+
+#[test]
+fn solve_for_outcome() {
+    assert_eq!(Shape::Rock.solve_for(Outcome::Win), Shape::Paper);
+    assert_eq!(Shape::Paper.solve_for(Outcome::Lose), Shape::Rock);
+    assert_eq!(Shape::Scissors.solve_for(Outcome::Draw), Shape::Scissors);
+}
+
+#[test]
+fn cyclic_rps_matches_puzzle() {
+    let puzzle = compute_score::<(Roshambo, FixedMatch)>(Day2::INPUT);
+
+    let cyclic = compute_score::<(Cyclic<Rps>, CyclicFixed<Rps>)>(Day2::INPUT);
+
+    assert_eq!(cyclic, puzzle);
+}
+
+#[test]
+fn cyclic_rpsls() {
+    const INPUT: &str = "A V\nB Z\nE Y\nD Z";
+
+    let scores = compute_score::<Cyclic<Rpsls>>(INPUT);
+
+    // Rock draws rock, lizard eats paper, Spock is poisoned by lizard, lizard poisons Spock.
+    assert_eq!(scores, (1 + 3) + (5 + 6) + 4 + (5 + 6));
+}
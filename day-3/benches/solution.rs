@@ -0,0 +1,44 @@
+use std::hint::black_box;
+
+use aoc_common::{
+    bench::{
+        bench_generated,
+        bench_solution,
+    },
+    solution::Solution,
+};
+use criterion::{
+    criterion_group,
+    criterion_main,
+    Criterion,
+};
+use day_3::{
+    intersect,
+    Day3,
+};
+
+fn synthetic() -> String {
+    format!("{}\n", Day3::INPUT.trim_end()).repeat(200)
+}
+
+fn solution(c: &mut Criterion) {
+    let synthetic = synthetic();
+    bench_solution::<Day3>(c, &[("given", Day3::INPUT), ("synthetic", &synthetic)]);
+}
+
+fn generated(c: &mut Criterion) {
+    bench_generated::<Day3>(c, &[100, 1_000, 10_000]);
+}
+
+fn bitset(c: &mut Criterion) {
+    let bags: [&[u8]; 3] = [
+        b"vJrwpWtwJgWrhcsFMMfFFhFp",
+        b"jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+        b"PmmdzqPrVvPwwTWBwg",
+    ];
+
+    c.bench_function("day-3/intersect", |b| b.iter(|| intersect(black_box(bags))));
+}
+
+criterion_group!(benches, solution, generated, bitset);
+criterion_main!(benches);
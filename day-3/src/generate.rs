@@ -0,0 +1,108 @@
+use aoc_common::generate::{
+    rand::{
+        seq::SliceRandom,
+        Rng,
+    },
+    Generator,
+};
+
+use crate::Day3;
+
+const ITEMS: &[u8; 52] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Fills a rucksack whose compartments only have `shared` in common, carrying the `badge` too.
+fn rucksack<R>(rng: &mut R, allowed: &[u8], shared: u8, badge: u8) -> Vec<u8>
+where
+    R: Rng,
+{
+    let mut others: Vec<_> = allowed
+        .iter()
+        .copied()
+        .filter(|&item| item != shared)
+        .collect();
+    others.shuffle(rng);
+    let (left_items, right_items) = others.split_at(others.len() / 2);
+
+    let len = rng.gen_range(2..=16);
+    let mut compartments = [left_items, right_items].map(|items| {
+        let mut compartment = vec![shared];
+        compartment.extend((1..len).map(|_| *items.choose(rng).unwrap()));
+        compartment
+    });
+
+    if badge != shared {
+        let side = usize::from(right_items.contains(&badge));
+        compartments[side][1] = badge;
+    }
+
+    compartments
+        .iter_mut()
+        .for_each(|compartment| compartment.shuffle(rng));
+    compartments.concat()
+}
+
+impl Generator for Day3 {
+    /// Generates `size` groups of three rucksacks.
+    ///
+    /// Every item other than the badge of a group is left out of at least one of its rucksacks, so
+    /// the badge is the only item the three have in common.
+    fn generate<R>(rng: &mut R, size: usize) -> String
+    where
+        R: Rng,
+    {
+        let mut input = String::new();
+        for _ in 0..size {
+            let mut items = *ITEMS;
+            items.shuffle(rng);
+            let (&badge, rest) = items.split_first().unwrap();
+            let pools: Vec<_> = rest.chunks(rest.len() / 3).collect();
+
+            for elf in 0..3 {
+                let mut allowed = vec![badge];
+                allowed.extend(pools[(elf + 1) % 3]);
+                allowed.extend(pools[(elf + 2) % 3]);
+                let shared = *allowed.choose(rng).unwrap();
+
+                let rucksack = rucksack(rng, &allowed, shared, badge);
+                input.extend(rucksack.into_iter().map(char::from));
+                input.push('\n');
+            }
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{
+        generate::seeded,
+        solution::solve,
+    };
+
+    use super::*;
+    use crate::intersect;
+
+    #[test]
+    fn generated_inputs_solve() {
+        for (seed, size) in [(0, 0), (1, 1), (2, 100), (3, 10_000)] {
+            let input = seeded::<Day3>(seed, size);
+            let lines: Vec<_> = input.lines().map(str::as_bytes).collect();
+
+            assert_eq!(lines.len(), 3 * size);
+            assert!(solve::<Day3>(&input, None).is_ok());
+            for rucksack in &lines {
+                let (left, right) = rucksack.split_at(rucksack.len() / 2);
+                let shared: Vec<_> = left.iter().filter(|item| right.contains(item)).collect();
+                assert!(shared.iter().all(|&item| item == shared[0]));
+                assert!(intersect([left, right]) > 0);
+            }
+            for group in lines.chunks(3) {
+                let badges = ITEMS
+                    .iter()
+                    .filter(|item| group.iter().all(|rucksack| rucksack.contains(item)))
+                    .count();
+                assert_eq!(badges, 1);
+            }
+        }
+    }
+}
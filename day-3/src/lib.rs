@@ -0,0 +1,110 @@
+#![cfg_attr(feature = "nightly", feature(iter_array_chunks))]
+
+use std::{
+    convert::Infallible,
+    ops::BitAnd,
+};
+
+use aoc_common::solution::Solution;
+
+pub mod generate;
+
+/// Priority of an item, from 1 to 52, or 0 if the byte is not an item.
+pub fn to_priority(c: u8) -> u8 {
+    match c {
+        c @ b'a'..=b'z' => c - b'a' + 1,
+        c @ b'A'..=b'Z' => c - b'A' + 27,
+        _ => 0,
+    }
+}
+
+fn union(acc: u64, bit: u8) -> u64 {
+    assert!(0 < bit && bit < 53);
+    acc | (1 << bit)
+}
+
+/// Priority of the single item type shared by every bag.
+///
+/// # Panics
+/// If the bags share no item.
+pub fn intersect<const N: usize>(arr: [&[u8]; N]) -> u32 {
+    let result = arr
+        .into_iter()
+        .map(|bag| bag.iter().copied().map(to_priority).fold(0, union))
+        .fold(u64::MAX, u64::bitand)
+        .trailing_zeros();
+
+    assert!(0 < result && result < 53);
+    result
+}
+
+/// Sums the priorities of the items found in both compartments of each rucksack.
+pub fn part_1(input: &str) -> u32 {
+    input
+        .lines()
+        .map(str::as_bytes)
+        .map(|l| l.split_at(l.len() / 2))
+        .map(|(left, right)| intersect([left, right]))
+        .sum()
+}
+
+/// Sums the priorities of the badges shared by each group of three elves.
+#[cfg(feature = "nightly")]
+pub fn part_2(input: &str) -> u32 {
+    input
+        .lines()
+        .map(str::as_bytes)
+        .array_chunks::<3>()
+        .map(intersect)
+        .sum()
+}
+
+/// Sums the priorities of the badges shared by each group of three elves.
+#[cfg(not(feature = "nightly"))]
+pub fn part_2(input: &str) -> u32 {
+    let lines: Vec<_> = input.lines().map(str::as_bytes).collect();
+    lines
+        .chunks_exact(3)
+        .map(|group| intersect([group[0], group[1], group[2]]))
+        .sum()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Answer1 = u32;
+    type Answer2 = u32;
+    type Error = Infallible;
+    type Parsed<'i> = &'i str;
+
+    const DAY: u8 = 3;
+    const INPUT: &'static str = include_str!("input/given.txt");
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+        Ok(input)
+    }
+
+    fn part_1(input: &Self::Parsed<'_>) -> Result<Self::Answer1, Self::Error> {
+        Ok(part_1(input))
+    }
+
+    fn part_2(input: &Self::Parsed<'_>) -> Result<Self::Answer2, Self::Error> {
+        Ok(part_2(input))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example_works() {
+        const INPUT: &str = include_str!("input/example.txt");
+
+        let sum_1 = part_1(INPUT);
+        let sum_2 = part_2(INPUT);
+
+        assert_eq!(sum_1, 157);
+        assert_eq!(sum_2, 70);
+    }
+}
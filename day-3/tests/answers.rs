@@ -0,0 +1,7 @@
+use aoc_common::answers;
+use day_3::Day3;
+
+#[test]
+fn recorded_answers() {
+    answers::verify::<Day3>(concat!(env!("CARGO_MANIFEST_DIR"), "/src/input"));
+}
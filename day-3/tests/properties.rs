@@ -0,0 +1,51 @@
+use day_3::{
+    intersect,
+    to_priority,
+};
+use proptest::{
+    collection::vec,
+    prelude::*,
+    sample::select,
+};
+
+const ITEMS: &[u8; 52] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Lowest priority of the items found in every bag, checking each priority in turn.
+fn naive_intersect(bags: &[Vec<u8>]) -> Option<u32> {
+    ITEMS
+        .iter()
+        .find(|item| bags.iter().all(|bag| bag.contains(item)))
+        .map(|&item| u32::from(to_priority(item)))
+}
+
+/// Bags of random items that all carry at least one common item.
+fn bags<const N: usize>() -> impl Strategy<Value = [Vec<u8>; N]> {
+    let items = || vec(select(&ITEMS[..]), 0..24);
+
+    (
+        select(&ITEMS[..]),
+        [(); N].map(|_| (items(), any::<prop::sample::Index>())),
+    )
+        .prop_map(|(common, bags)| {
+            bags.map(|(mut bag, index)| {
+                bag.insert(index.index(bag.len() + 1), common);
+                bag
+            })
+        })
+}
+
+proptest! {
+    #[test]
+    fn intersect_compartments(bags in bags::<2>()) {
+        let expected = naive_intersect(&bags);
+
+        prop_assert_eq!(Some(intersect(bags.each_ref().map(Vec::as_slice))), expected);
+    }
+
+    #[test]
+    fn intersect_groups(bags in bags::<3>()) {
+        let expected = naive_intersect(&bags);
+
+        prop_assert_eq!(Some(intersect(bags.each_ref().map(Vec::as_slice))), expected);
+    }
+}
@@ -0,0 +1,36 @@
+use aoc_common::solution::Solution;
+use day_3::{
+    intersect,
+    part_1,
+    part_2,
+    to_priority,
+    Day3,
+};
+
+const EXAMPLE: &str = include_str!("../src/input/example.txt");
+
+#[test]
+fn priorities() {
+    assert_eq!(to_priority(b'a'), 1);
+    assert_eq!(to_priority(b'z'), 26);
+    assert_eq!(to_priority(b'A'), 27);
+    assert_eq!(to_priority(b'Z'), 52);
+    assert_eq!(to_priority(b'-'), 0);
+}
+
+#[test]
+fn intersect_bags() {
+    let priority = intersect([b"vJrwpWtwJgWr".as_slice(), b"hcsFMMfFFhFp"]);
+
+    assert_eq!(priority, u32::from(to_priority(b'p')));
+}
+
+#[test]
+fn example_solution() {
+    let rucksacks = Day3::parse(EXAMPLE).unwrap();
+
+    assert_eq!(part_1(EXAMPLE), 157);
+    assert_eq!(part_2(EXAMPLE), 70);
+    assert_eq!(Day3::part_1(&rucksacks).unwrap(), 157);
+    assert_eq!(Day3::part_2(&rucksacks).unwrap(), 70);
+}
@@ -0,0 +1,29 @@
+use aoc_common::{
+    bench::{
+        bench_generated,
+        bench_solution,
+    },
+    solution::Solution,
+};
+use criterion::{
+    criterion_group,
+    criterion_main,
+    Criterion,
+};
+use day_4::Day4;
+
+fn synthetic() -> String {
+    format!("{}\n", Day4::INPUT.trim_end()).repeat(200)
+}
+
+fn solution(c: &mut Criterion) {
+    let synthetic = synthetic();
+    bench_solution::<Day4>(c, &[("given", Day4::INPUT), ("synthetic", &synthetic)]);
+}
+
+fn generated(c: &mut Criterion) {
+    bench_generated::<Day4>(c, &[1_000, 10_000, 100_000]);
+}
+
+criterion_group!(benches, solution, generated);
+criterion_main!(benches);
@@ -0,0 +1,52 @@
+use std::fmt::Write;
+
+use aoc_common::generate::{
+    rand::Rng,
+    Generator,
+};
+
+use crate::Day4;
+
+impl Generator for Day4 {
+    /// Generates `size` pairs of section assignments.
+    fn generate<R>(rng: &mut R, size: usize) -> String
+    where
+        R: Rng,
+    {
+        let range = |rng: &mut R| {
+            let start = rng.gen_range(1..=99);
+            (start, rng.gen_range(start..=99))
+        };
+
+        let mut input = String::new();
+        for _ in 0..size {
+            let (a, b) = range(rng);
+            let (c, d) = range(rng);
+            writeln!(input, "{a}-{b},{c}-{d}").unwrap();
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{
+        generate::seeded,
+        solution::{
+            solve,
+            Solution,
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn generated_inputs_solve() {
+        for (seed, size) in [(0, 0), (1, 1), (2, 100), (3, 10_000)] {
+            let input = seeded::<Day4>(seed, size);
+
+            assert_eq!(Day4::parse(&input).unwrap().len(), size);
+            assert!(solve::<Day4>(&input, None).is_ok());
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use std::ops::RangeInclusive;
+
+use aoc_common::{
+    parser,
+    solution::Solution,
+};
+
+pub mod generate;
+
+pub type Range = RangeInclusive<u32>;
+
+pub fn parse_range(input: &str) -> parser::Result<'_, Range> {
+    parser::map(
+        parser::separated_pair(parser::u32, parser::char('-'), parser::u32),
+        |(a, b)| a..=b,
+    )(input)
+}
+
+pub fn parse_line(input: &str) -> parser::Result<'_, (Range, Range)> {
+    parser::separated_pair(parse_range, parser::char(','), parse_range)(input)
+}
+
+/// Parses the pair of section assignments of every line.
+pub fn parse_input(input: &str) -> parser::Result<'_, Vec<(Range, Range)>> {
+    parser::lines(parse_line)(input)
+}
+
+/// Whether one of the assignments fully contains the other.
+pub fn contains_whole((left, right): &(Range, Range)) -> bool {
+    (left.contains(right.start()) && left.contains(right.end()))
+        || (right.contains(left.start()) && right.contains(left.end()))
+}
+
+/// Whether the assignments have any section in common.
+pub fn overlaps((left, right): &(Range, Range)) -> bool {
+    left.contains(right.start())
+        || left.contains(right.end())
+        || right.contains(left.start())
+        || right.contains(left.end())
+}
+
+/// Folds the pairs into the number of redundant and overlapping assignments.
+pub fn count(
+    (redundant_count, overlap_count): (usize, usize),
+    schedules: (Range, Range),
+) -> (usize, usize) {
+    let redundant_incr = usize::from(contains_whole(&schedules));
+    let overlap_incr = usize::from(overlaps(&schedules));
+
+    (
+        redundant_count + redundant_incr,
+        overlap_count + overlap_incr,
+    )
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Error = parser::Error;
+    type Parsed<'i> = Vec<(Range, Range)>;
+
+    const DAY: u8 = 4;
+    const INPUT: &'static str = include_str!("input/given.txt");
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+        parser::parse_all(input, parse_input)
+    }
+
+    fn part_1(ranges: &Self::Parsed<'_>) -> Result<Self::Answer1, Self::Error> {
+        Ok(ranges.iter().cloned().fold((0, 0), count).0)
+    }
+
+    fn part_2(ranges: &Self::Parsed<'_>) -> Result<Self::Answer2, Self::Error> {
+        Ok(ranges.iter().cloned().fold((0, 0), count).1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::parser::nom::{
+        error::{
+            Error,
+            ErrorKind,
+        },
+        Err,
+    };
+
+    use super::*;
+
+    #[test]
+    fn parse_range_fails() {
+        const INPUT: &str = "9-";
+
+        let result = parse_range(INPUT);
+
+        assert_eq!(
+            result,
+            Err(Err::Error(Error { input: "", code: ErrorKind::Digit }))
+        );
+    }
+
+    #[test]
+    fn parse_range_works() {
+        const INPUT: &str = "9-12";
+
+        let result = parse_range(INPUT);
+
+        assert_eq!(result, Ok(("", 9..=12)));
+    }
+
+    #[test]
+    fn parse_line_works() {
+        const INPUT: &str = "9-12,11-13";
+
+        let result = parse_line(INPUT);
+
+        assert_eq!(result, Ok(("", (9..=12, 11..=13))));
+    }
+
+    #[test]
+    fn parse_input_works() {
+        const INPUT: &str = "9-12,11-13\n9-12,11-13";
+
+        let result = parse_input(INPUT);
+
+        assert_eq!(result, Ok(("", vec![(9..=12, 11..=13), (9..=12, 11..=13)])));
+    }
+
+    #[test]
+    fn example_works() {
+        const INPUT: &str = include_str!("input/example.txt");
+
+        let (rest, ranges) = parse_input(INPUT).unwrap();
+        let (redundant, overlapping) = ranges.into_iter().fold((0, 0), count);
+
+        assert_eq!(rest, "");
+        assert_eq!(redundant, 2);
+        assert_eq!(overlapping, 4);
+    }
+
+    #[test]
+    fn given_works() {
+        const INPUT: &str = include_str!("input/given.txt");
+
+        let (rest, ranges) = parse_input(INPUT).unwrap();
+        let (redundant, overlapping) = ranges.into_iter().fold((0, 0), count);
+
+        assert_eq!(rest, "");
+        assert_eq!(redundant, 532);
+        assert_eq!(overlapping, 854);
+    }
+}
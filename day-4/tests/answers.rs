@@ -0,0 +1,7 @@
+use aoc_common::answers;
+use day_4::Day4;
+
+#[test]
+fn recorded_answers() {
+    answers::verify::<Day4>(concat!(env!("CARGO_MANIFEST_DIR"), "/src/input"));
+}
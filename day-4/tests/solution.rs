@@ -0,0 +1,36 @@
+use aoc_common::solution::Solution;
+use day_4::{
+    contains_whole,
+    count,
+    overlaps,
+    parse_input,
+    Day4,
+};
+
+const EXAMPLE: &str = include_str!("../src/input/example.txt");
+
+#[test]
+fn classify_pairs() {
+    assert!(contains_whole(&(2..=8, 3..=7)));
+    assert!(overlaps(&(2..=8, 3..=7)));
+    assert!(!contains_whole(&(5..=7, 7..=9)));
+    assert!(overlaps(&(5..=7, 7..=9)));
+    assert!(!overlaps(&(2..=4, 6..=8)));
+}
+
+#[test]
+fn example_solution() {
+    let (_, pairs) = parse_input(EXAMPLE).unwrap();
+    let ranges = Day4::parse(EXAMPLE).unwrap();
+
+    assert_eq!(pairs.into_iter().fold((0, 0), count), (2, 4));
+    assert_eq!(Day4::part_1(&ranges).unwrap(), 2);
+    assert_eq!(Day4::part_2(&ranges).unwrap(), 4);
+}
+
+#[test]
+fn invalid_input() {
+    let result = Day4::parse("2-4,6-8\n2-3;4-5\n");
+
+    assert!(result.is_err());
+}
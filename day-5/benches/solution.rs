@@ -0,0 +1,52 @@
+use std::str::FromStr;
+
+use aoc_common::{
+    bench::{
+        bench_generated,
+        bench_solution,
+    },
+    solution::Solution,
+};
+use criterion::{
+    criterion_group,
+    criterion_main,
+    Criterion,
+};
+use day_5::{
+    command::Command,
+    split_input,
+    Day5,
+};
+
+/// Repeats the given procedure followed by its inverse, which restores the starting stacks.
+fn synthetic() -> String {
+    let (stacks, cmds) = split_input(Day5::INPUT).unwrap();
+    let cmds: Vec<_> = cmds
+        .lines()
+        .map(|line| Command::from_str(line).unwrap())
+        .collect();
+
+    let forward = cmds.iter().copied();
+    let backward =
+        cmds.iter()
+            .rev()
+            .map(|&Command { n, from, to }| Command { n, from: to, to: from });
+    let round: Vec<_> = forward
+        .chain(backward)
+        .map(|Command { n, from, to }| format!("move {n} from {} to {}", from + 1, to + 1))
+        .collect();
+
+    format!("{stacks}\n\n{}\n", vec![round.join("\n"); 100].join("\n"))
+}
+
+fn solution(c: &mut Criterion) {
+    let synthetic = synthetic();
+    bench_solution::<Day5>(c, &[("given", Day5::INPUT), ("synthetic", &synthetic)]);
+}
+
+fn generated(c: &mut Criterion) {
+    bench_generated::<Day5>(c, &[1_000, 10_000, 100_000]);
+}
+
+criterion_group!(benches, solution, generated);
+criterion_main!(benches);
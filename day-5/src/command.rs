@@ -1,5 +1,6 @@
+#[cfg(feature = "nightly")]
+use std::array;
 use std::{
-    array,
     num::{
         NonZeroUsize,
         ParseIntError,
@@ -56,11 +57,19 @@ impl FromStr for Command {
             .captures(line)
             .with_context(|| error::InvalidCommand { input: line })?;
 
-        let [n, from, to]: [NonZeroUsize; 3] = array::try_from_fn(|i| {
-            let name = NAMES[i];
+        let parse = |name: &'static str| {
             let arg = &captures[name];
-            arg.parse().context(error::InvalidArg { name, arg })
-        })?;
+            arg.parse::<NonZeroUsize>()
+                .context(error::InvalidArg { name, arg })
+        };
+
+        #[cfg(feature = "nightly")]
+        let [n, from, to] = array::try_from_fn(|i| parse(NAMES[i]))?;
+        #[cfg(not(feature = "nightly"))]
+        let [n, from, to] = {
+            let [n, from, to] = NAMES.map(parse);
+            [n?, from?, to?]
+        };
 
         ensure!(from != to, error::SameSourceDest { stack: from });
 
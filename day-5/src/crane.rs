@@ -24,7 +24,11 @@ impl Crane for BaseCrane {
             error::MissingItems { count: n, len: from.len() }
         );
 
-        from.drain((from.len() - n)..).rev().collect_into(to);
+        let moved = from.drain((from.len() - n)..).rev();
+        #[cfg(feature = "nightly")]
+        moved.collect_into(to);
+        #[cfg(not(feature = "nightly"))]
+        to.extend(moved);
         Ok(())
     }
 }
@@ -38,7 +42,11 @@ impl Crane for ManyCrane {
             error::MissingItems { count: n, len: from.len() }
         );
 
-        from.drain((from.len() - n)..).collect_into(to);
+        let moved = from.drain((from.len() - n)..);
+        #[cfg(feature = "nightly")]
+        moved.collect_into(to);
+        #[cfg(not(feature = "nightly"))]
+        to.extend(moved);
         Ok(())
     }
 }
@@ -0,0 +1,113 @@
+use std::fmt::Write;
+
+use aoc_common::generate::{
+    rand::{
+        seq::{
+            IteratorRandom,
+            SliceRandom,
+        },
+        Rng,
+    },
+    Generator,
+};
+
+use crate::Day5;
+
+const CRATES: &[u8; 26] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Draws the stacks as in the puzzle, from the top crates down to the line of stack numbers.
+fn draw(stacks: &[Vec<u8>]) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+
+    let mut drawing = String::new();
+    for level in (0..height).rev() {
+        let line: Vec<_> = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(&item) => format!("[{}]", char::from(item)),
+                None => "   ".to_owned(),
+            })
+            .collect();
+        writeln!(drawing, "{}", line.join(" ").trim_end()).unwrap();
+    }
+
+    let numbers: Vec<_> = (1..=stacks.len()).map(|n| format!(" {n} ")).collect();
+    writeln!(drawing, "{}", numbers.join(" ")).unwrap();
+    drawing
+}
+
+impl Generator for Day5 {
+    /// Generates a drawing of two to nine stacks followed by a procedure of `size` moves, or a
+    /// single move when none are asked for, since the procedure cannot be empty.
+    ///
+    /// The moves are simulated while generating, so they never take more crates than a stack
+    /// holds.
+    fn generate<R>(rng: &mut R, size: usize) -> String
+    where
+        R: Rng,
+    {
+        let mut stacks: Vec<Vec<u8>> = (0..rng.gen_range(2..=9))
+            .map(|_| {
+                (0..rng.gen_range(1..=48))
+                    .map(|_| *CRATES.choose(rng).unwrap())
+                    .collect()
+            })
+            .collect();
+
+        let mut input = draw(&stacks);
+        input.push('\n');
+
+        for _ in 0..size.max(1) {
+            let from = (0..stacks.len())
+                .filter(|&from| !stacks[from].is_empty())
+                .choose(rng)
+                .unwrap();
+            let to = (0..stacks.len())
+                .filter(|&to| to != from)
+                .choose(rng)
+                .unwrap();
+            let n = rng.gen_range(1..=stacks[from].len());
+
+            let at = stacks[from].len() - n;
+            let moved = stacks[from].split_off(at);
+            stacks[to].extend(moved);
+            writeln!(input, "move {n} from {} to {}", from + 1, to + 1).unwrap();
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{
+        generate::seeded,
+        solution::{
+            solve,
+            Solution,
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn draw_works() {
+        let stacks = [b"ZN".to_vec(), b"MCD".to_vec(), b"P".to_vec()];
+
+        assert_eq!(
+            draw(&stacks),
+            "    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3 \n"
+        );
+    }
+
+    #[test]
+    fn generated_inputs_solve() {
+        for (seed, size) in [(0, 0), (1, 1), (2, 100), (3, 10_000)] {
+            let input = seeded::<Day5>(seed, size);
+            let (stacks, cmds) = Day5::parse(&input).unwrap();
+
+            assert_eq!(cmds.len(), size.max(1));
+            assert!(!stacks.items_on_top().is_empty());
+            assert!(solve::<Day5>(&input, None).is_ok());
+        }
+    }
+}
@@ -0,0 +1,132 @@
+#![cfg_attr(feature = "nightly", feature(iter_collect_into, array_try_from_fn))]
+
+use std::str::FromStr;
+
+use aoc_common::{
+    groups::groups,
+    solution::Solution,
+};
+use snafu::prelude::*;
+
+use crate::{
+    command::Command,
+    crane::{
+        BaseCrane,
+        Crane,
+        ManyCrane,
+    },
+    stacks::Stacks,
+};
+
+pub mod command;
+pub mod crane;
+pub mod generate;
+pub mod stacks;
+
+#[non_exhaustive]
+#[derive(Debug, Snafu)]
+#[snafu(module(error), context(suffix(false)))]
+pub enum Error {
+    #[snafu(display("missing the blank line between the stacks and the commands"))]
+    MissingSeparator,
+    #[snafu(display("unable to parse command"))]
+    ParseCommand { source: command::Error },
+    #[snafu(display("unable to execute command"))]
+    ExecuteCommand { source: stacks::ExecuteError },
+}
+
+/// Splits the input into the starting stacks drawing and the rearrangement procedure.
+pub fn split_input(input: &str) -> Result<(&str, &str), Error> {
+    let mut groups = groups(input);
+    groups
+        .next()
+        .zip(groups.next())
+        .context(error::MissingSeparator)
+}
+
+/// Runs the procedure over a copy of the stacks, returning the crates left on top.
+pub fn rearrange<C>(stacks: &Stacks, cmds: &[Command]) -> Result<String, Error>
+where
+    C: Crane,
+{
+    let mut stacks = stacks.clone();
+    for &cmd in cmds {
+        stacks.execute::<C>(cmd).context(error::ExecuteCommand)?;
+    }
+    Ok(stacks.items_on_top())
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Answer1 = String;
+    type Answer2 = String;
+    type Error = Error;
+    type Parsed<'i> = (Stacks, Vec<Command>);
+
+    const DAY: u8 = 5;
+    const INPUT: &'static str = include_str!("input/given.txt");
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+        let (stack, cmds) = split_input(input)?;
+
+        let stacks = Stacks::from_input(stack);
+        let cmds = cmds
+            .lines()
+            .map(Command::from_str)
+            .collect::<Result<_, _>>()
+            .context(error::ParseCommand)?;
+
+        Ok((stacks, cmds))
+    }
+
+    fn part_1((stacks, cmds): &Self::Parsed<'_>) -> Result<Self::Answer1, Self::Error> {
+        rearrange::<BaseCrane>(stacks, cmds)
+    }
+
+    fn part_2((stacks, cmds): &Self::Parsed<'_>) -> Result<Self::Answer2, Self::Error> {
+        rearrange::<ManyCrane>(stacks, cmds)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example_works() {
+        const INPUT: &str = include_str!("input/example.txt");
+
+        let (stack, cmds) = split_input(INPUT).unwrap();
+        let mut stacks = Stacks::from_input(stack);
+        let mut rev_stacks = stacks.clone();
+
+        for cmd in cmds.lines().map(Command::from_str) {
+            let cmd = cmd.unwrap();
+            rev_stacks.execute::<BaseCrane>(cmd).unwrap();
+            stacks.execute::<ManyCrane>(cmd).unwrap();
+        }
+
+        assert_eq!(rev_stacks.items_on_top(), "CMZ");
+        assert_eq!(stacks.items_on_top(), "MCD");
+    }
+
+    #[test]
+    fn solution_works() {
+        const INPUT: &str = include_str!("input/example.txt");
+
+        let parsed = Day5::parse(INPUT).unwrap();
+
+        assert_eq!(Day5::part_1(&parsed).unwrap(), "CMZ");
+        assert_eq!(Day5::part_2(&parsed).unwrap(), "MCD");
+    }
+
+    #[test]
+    fn split_input_fails() {
+        const INPUT: &str = "[A]\nmove 1 from 1 to 2";
+
+        let error = split_input(INPUT).unwrap_err();
+
+        assert!(matches!(error, Error::MissingSeparator));
+    }
+}
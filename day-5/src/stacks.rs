@@ -1,4 +1,3 @@
-use core::slice::GetManyMutError;
 use std::{
     fmt,
     fmt::{
@@ -6,6 +5,7 @@ use std::{
         Formatter,
     },
     iter,
+    slice::GetDisjointMutError,
     sync::OnceLock,
 };
 
@@ -26,7 +26,7 @@ pub enum ExecuteError {
     SameStack { stack: usize },
     #[snafu(display("cannot index stacks {} and {} from {len} total", indices[0], indices[1]))]
     OutOfBounds {
-        source: GetManyMutError<2>,
+        source: GetDisjointMutError,
         len: usize,
         indices: [usize; 2],
     },
@@ -55,8 +55,7 @@ impl Stacks {
 
     fn push_line(&mut self, line: Vec<Option<u8>>) {
         if self.0.len() < line.len() {
-            self.0
-                .extend(iter::repeat(Vec::new()).take(line.len() - self.0.len()))
+            self.0.resize_with(line.len(), Vec::new);
         }
 
         for (stack, item) in iter::zip(&mut self.0, line) {
@@ -111,7 +110,7 @@ impl Stacks {
         let len = self.0.len();
         let [origin, dest] = self
             .0
-            .get_many_mut([from, to])
+            .get_disjoint_mut([from, to])
             .context(error::OutOfBounds { len, indices: [from, to] })?;
 
         C::move_items(n, origin, dest).context(error::MissingItems { from })
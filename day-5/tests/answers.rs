@@ -0,0 +1,7 @@
+use aoc_common::answers;
+use day_5::Day5;
+
+#[test]
+fn recorded_answers() {
+    answers::verify::<Day5>(concat!(env!("CARGO_MANIFEST_DIR"), "/src/input"));
+}
@@ -0,0 +1,46 @@
+use std::str::FromStr;
+
+use aoc_common::solution::Solution;
+use day_5::{
+    command::Command,
+    crane::{
+        BaseCrane,
+        ManyCrane,
+    },
+    rearrange,
+    split_input,
+    stacks::Stacks,
+    Day5,
+};
+
+const EXAMPLE: &str = include_str!("../src/input/example.txt");
+
+#[test]
+fn example_rearrange() {
+    let (stack, cmds) = split_input(EXAMPLE).unwrap();
+    let stacks = Stacks::from_input(stack);
+    let cmds: Vec<_> = cmds
+        .lines()
+        .map(Command::from_str)
+        .map(Result::unwrap)
+        .collect();
+
+    assert_eq!(rearrange::<BaseCrane>(&stacks, &cmds).unwrap(), "CMZ");
+    assert_eq!(rearrange::<ManyCrane>(&stacks, &cmds).unwrap(), "MCD");
+    assert_eq!(stacks.items_on_top(), "NDP");
+}
+
+#[test]
+fn example_solution() {
+    let procedure = Day5::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day5::part_1(&procedure).unwrap(), "CMZ");
+    assert_eq!(Day5::part_2(&procedure).unwrap(), "MCD");
+}
+
+#[test]
+fn invalid_procedure() {
+    let procedure = Day5::parse("[A]\n\nmove 2 from 1 to 2").unwrap();
+
+    assert!(Day5::part_1(&procedure).is_err());
+}
@@ -0,0 +1,45 @@
+use std::hint::black_box;
+
+use aoc_common::{
+    bench::{
+        bench_generated,
+        bench_solution,
+    },
+    solution::Solution,
+};
+use criterion::{
+    criterion_group,
+    criterion_main,
+    Criterion,
+};
+use day_6::{
+    compute_increment_14,
+    compute_increment_4,
+    Day6,
+};
+
+/// Prefixes the given stream with a long run that never has four unique values in a row.
+fn synthetic() -> String {
+    "abc".repeat(100_000) + Day6::INPUT
+}
+
+fn solution(c: &mut Criterion) {
+    let synthetic = synthetic();
+    bench_solution::<Day6>(c, &[("given", Day6::INPUT), ("synthetic", &synthetic)]);
+}
+
+fn generated(c: &mut Criterion) {
+    bench_generated::<Day6>(c, &[1_000, 100_000, 1_000_000]);
+}
+
+fn increments(c: &mut Criterion) {
+    c.bench_function("day-6/increment_4", |b| {
+        b.iter(|| compute_increment_4(black_box(b"1231")))
+    });
+    c.bench_function("day-6/increment_14", |b| {
+        b.iter(|| compute_increment_14(black_box(b"abcdefghij1231")))
+    });
+}
+
+criterion_group!(benches, solution, generated, increments);
+criterion_main!(benches);
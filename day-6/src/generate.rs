@@ -0,0 +1,58 @@
+use aoc_common::generate::{
+    rand::{
+        seq::SliceRandom,
+        Rng,
+    },
+    Generator,
+};
+
+use crate::Day6;
+
+const LETTERS: &[u8; 26] = b"abcdefghijklmnopqrstuvwxyz";
+
+impl Generator for Day6 {
+    /// Generates a datastream of `size` characters followed by a start-of-message marker.
+    ///
+    /// The stream draws from at most thirteen letters and the marker starts with its last letter,
+    /// so the marker is the first sequence of fourteen unique characters. Streams of three letters
+    /// also push the start-of-packet marker to the end.
+    fn generate<R>(rng: &mut R, size: usize) -> String
+    where
+        R: Rng,
+    {
+        let mut letters = *LETTERS;
+        letters.shuffle(rng);
+        let alphabet = &letters[..rng.gen_range(3..=13)];
+
+        let mut stream: Vec<_> = (0..size).map(|_| *alphabet.choose(rng).unwrap()).collect();
+        let last = stream.last().copied();
+        letters.shuffle(rng);
+        let others = letters.into_iter().filter(|&letter| Some(letter) != last);
+
+        stream.extend(last.into_iter().chain(others).take(14));
+        stream.push(b'\n');
+
+        String::from_utf8(stream).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{
+        generate::seeded,
+        solution::Solution,
+    };
+
+    use super::*;
+
+    #[test]
+    fn generated_inputs_solve() {
+        for (seed, size) in [(0, 0), (1, 1), (2, 100), (3, 10_000)] {
+            let input = seeded::<Day6>(seed, size);
+
+            assert_eq!(input.len(), size + 15);
+            assert!(Day6::part_1(&input.as_str()).unwrap() <= size + 14);
+            assert_eq!(Day6::part_2(&input.as_str()).unwrap(), size + 14);
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! Increments computed one value at a time, for toolchains without portable SIMD.
+
+/// Scans the values from the end, stopping at the last one repeated later in the sequence.
+fn increment<const N: usize>(arr: &[u8; N]) -> usize {
+    let mut seen = [false; 256];
+    for (index, &value) in arr.iter().enumerate().rev() {
+        if seen[usize::from(value)] {
+            return index + 1;
+        }
+        seen[usize::from(value)] = true;
+    }
+    0
+}
+
+pub fn increment_4(arr: &[u8; 4]) -> usize {
+    increment(arr)
+}
+
+pub fn increment_14(arr: &[u8; 14]) -> usize {
+    increment(arr)
+}
@@ -0,0 +1,66 @@
+//! Increments computed by comparing every pair of values at once with portable SIMD.
+
+use std::{
+    array,
+    cmp::max,
+    convert::identity,
+    ops::{
+        BitXor,
+        Not,
+    },
+    simd::{
+        cmp::{
+            SimdOrd,
+            SimdPartialEq,
+            SimdPartialOrd,
+        },
+        num::SimdUint,
+        Mask,
+        Select,
+        Simd,
+    },
+};
+
+pub fn increment_4(arr: &[u8; 4]) -> usize {
+    let mask = Mask::splat(true);
+    let default = Simd::splat(0);
+
+    let cols = Simd::from_array([0, 1, 2, 3, 0, 1, 2, 3, 0, 1, 2, 3, 0, 1, 2, 3]);
+    let rows = Simd::from_array([0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3]);
+    let incr = Simd::from_array([0, 1, 1, 1, 1, 0, 2, 2, 1, 2, 0, 3, 1, 2, 3, 0]);
+
+    let cols = unsafe { Simd::gather_select_unchecked(arr, mask, cols, default) };
+    let rows = unsafe { Simd::gather_select_unchecked(arr, mask, rows, default) };
+
+    let max_incr = cols.simd_eq(rows).select(incr, default).reduce_max();
+
+    usize::from(max_incr)
+}
+
+pub fn increment_14(arr: &[u8; 14]) -> usize {
+    let indices = Simd::from_array(array::from_fn(identity));
+    let pick = indices.simd_lt(Simd::splat(14));
+    let cols = unsafe { Simd::gather_select_unchecked(arr, pick, indices, Simd::splat(0)) };
+
+    let increments: Simd<u8, 16> = indices.cast();
+    let increments: Simd<u8, 16> = increments + Simd::splat(1);
+    let mut result = 0;
+    for (index, val) in arr.iter().copied().enumerate().rev() {
+        let row_index = Simd::splat((index + 1) as u8);
+        let increments = increments
+            .simd_eq(row_index)
+            .bitxor(pick.not().cast())
+            .select(Simd::splat(0), increments)
+            .simd_min(row_index);
+
+        let row = Simd::splat(val);
+        let incr = cols
+            .simd_eq(row)
+            .select(increments, Simd::splat(0))
+            .reduce_max();
+
+        result = max(result, usize::from(incr));
+    }
+
+    result
+}
@@ -0,0 +1,7 @@
+use aoc_common::answers;
+use day_6::Day6;
+
+#[test]
+fn recorded_answers() {
+    answers::verify::<Day6>(concat!(env!("CARGO_MANIFEST_DIR"), "/src/input"));
+}
@@ -0,0 +1,51 @@
+use day_6::{
+    compute_increment_14,
+    compute_increment_4,
+    find_post_unique_index,
+};
+use proptest::prelude::*;
+
+/// Skips past the first value of the last pair of equal values, comparing every pair in turn.
+fn naive_increment(arr: &[u8]) -> usize {
+    (0..arr.len())
+        .flat_map(|i| (i + 1..arr.len()).map(move |j| (i, j)))
+        .filter(|&(i, j)| arr[i] == arr[j])
+        .map(|(i, _)| i + 1)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Finds the first window of `n` unique values by checking every window in turn.
+fn naive_post_unique_index(input: &str, n: usize) -> Option<usize> {
+    input
+        .as_bytes()
+        .windows(n)
+        .position(|window| naive_increment(window) == 0)
+        .map(|index| index + n)
+}
+
+proptest! {
+    #[test]
+    fn increment_4(arr in prop::array::uniform4(b'a'..b'f')) {
+        prop_assert_eq!(compute_increment_4(&arr), naive_increment(&arr));
+    }
+
+    #[test]
+    fn increment_14(arr in prop::array::uniform14(b'a'..b'r')) {
+        prop_assert_eq!(compute_increment_14(&arr), naive_increment(&arr));
+    }
+
+    #[test]
+    fn packet_marker(input in "[a-f]{0,64}") {
+        let expected = naive_post_unique_index(&input, 4);
+
+        prop_assert_eq!(find_post_unique_index(&input, compute_increment_4), expected);
+    }
+
+    #[test]
+    fn message_marker(input in "[a-r]{0,256}") {
+        let expected = naive_post_unique_index(&input, 14);
+
+        prop_assert_eq!(find_post_unique_index(&input, compute_increment_14), expected);
+    }
+}
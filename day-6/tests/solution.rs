@@ -0,0 +1,36 @@
+use aoc_common::solution::Solution;
+use day_6::{
+    compute_increment_14,
+    compute_increment_4,
+    find_post_unique_index,
+    Day6,
+};
+
+const EXAMPLE: &str = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+
+#[test]
+fn example_markers() {
+    assert_eq!(
+        find_post_unique_index(EXAMPLE, compute_increment_4),
+        Some(7)
+    );
+    assert_eq!(
+        find_post_unique_index(EXAMPLE, compute_increment_14),
+        Some(19)
+    );
+}
+
+#[test]
+fn example_solution() {
+    let stream = Day6::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day6::part_1(&stream).unwrap(), 7);
+    assert_eq!(Day6::part_2(&stream).unwrap(), 19);
+}
+
+#[test]
+fn missing_marker() {
+    let stream = Day6::parse("abcabcabc").unwrap();
+
+    assert!(Day6::part_1(&stream).is_err());
+}
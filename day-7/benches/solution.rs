@@ -0,0 +1,30 @@
+use aoc_common::{
+    bench::{
+        bench_generated,
+        bench_solution,
+    },
+    solution::Solution,
+};
+use criterion::{
+    criterion_group,
+    criterion_main,
+    Criterion,
+};
+use day_7::Day7;
+
+/// Replays the given session many times, each one listing the same tree again from the root.
+fn synthetic() -> String {
+    format!("{}\n", Day7::INPUT.trim_end()).repeat(200)
+}
+
+fn solution(c: &mut Criterion) {
+    let synthetic = synthetic();
+    bench_solution::<Day7>(c, &[("given", Day7::INPUT), ("synthetic", &synthetic)]);
+}
+
+fn generated(c: &mut Criterion) {
+    bench_generated::<Day7>(c, &[100, 1_000, 10_000]);
+}
+
+criterion_group!(benches, solution, generated);
+criterion_main!(benches);
@@ -0,0 +1,113 @@
+use std::{
+    collections::HashSet,
+    fmt::Write,
+};
+
+use aoc_common::generate::{
+    rand::{
+        seq::SliceRandom,
+        Rng,
+    },
+    Generator,
+};
+
+use crate::Day7;
+
+const LETTERS: &[u8; 26] = b"abcdefghijklmnopqrstuvwxyz";
+const EXTENSIONS: [&str; 5] = ["", ".txt", ".dat", ".log", ".bin"];
+
+#[derive(Default)]
+struct Dir {
+    name: String,
+    dirs: Vec<usize>,
+    files: Vec<(String, usize)>,
+}
+
+fn name<R>(rng: &mut R) -> String
+where
+    R: Rng,
+{
+    (0..rng.gen_range(1..=8))
+        .map(|_| char::from(*LETTERS.choose(rng).unwrap()))
+        .collect()
+}
+
+/// Lists the content of the directory, then visits each of its subdirectories in turn.
+fn explore(tree: &[Dir], dir: usize, session: &mut String) {
+    let Dir { dirs, files, .. } = &tree[dir];
+
+    session.push_str("$ ls\n");
+    for &sub_dir in dirs {
+        writeln!(session, "dir {}", tree[sub_dir].name).unwrap();
+    }
+    for (name, size) in files {
+        writeln!(session, "{size} {name}").unwrap();
+    }
+
+    for &sub_dir in dirs {
+        writeln!(session, "$ cd {}", tree[sub_dir].name).unwrap();
+        explore(tree, sub_dir, session);
+        session.push_str("$ cd ..\n");
+    }
+}
+
+impl Generator for Day7 {
+    /// Generates a terminal session exploring a filesystem with `size` directories besides the
+    /// root.
+    fn generate<R>(rng: &mut R, size: usize) -> String
+    where
+        R: Rng,
+    {
+        let mut tree = vec![Dir::default()];
+        let mut names = vec![HashSet::new()];
+        for dir in 1..=size {
+            let parent = rng.gen_range(0..dir);
+            let name = loop {
+                let name = name(rng);
+                if names[parent].insert(name.clone()) {
+                    break name;
+                }
+            };
+
+            tree[parent].dirs.push(dir);
+            tree.push(Dir { name, ..Dir::default() });
+            names.push(HashSet::new());
+        }
+
+        for (dir, Dir { files, .. }) in tree.iter_mut().enumerate() {
+            let min_files = usize::from(dir == 0);
+            for _ in 0..rng.gen_range(min_files..=4) {
+                let name = name(rng) + EXTENSIONS.choose(rng).unwrap();
+                files.push((name, rng.gen_range(1..=300_000)));
+            }
+        }
+
+        let mut session = "$ cd /\n".to_owned();
+        explore(&tree, 0, &mut session);
+        session
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{
+        generate::seeded,
+        solution::{
+            solve,
+            Solution,
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn generated_inputs_solve() {
+        for (seed, size) in [(0, 0), (1, 1), (2, 100), (3, 10_000)] {
+            let input = seeded::<Day7>(seed, size);
+            let dir_sizes = Day7::parse(&input).unwrap();
+
+            assert!(dir_sizes.len() <= size + 1);
+            assert!(solve::<Day7>(&input, None).is_ok());
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use std::{
+    collections::HashMap,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use aoc_common::{
+    parser,
+    parser::{
+        nom::{
+            branch::alt,
+            bytes::complete::tag,
+            character::complete::{
+                alpha1,
+                not_line_ending,
+                space0,
+                space1,
+                u64,
+            },
+            Parser,
+        },
+        parse_all,
+    },
+    solution::Solution,
+};
+use snafu::prelude::*;
+
+pub mod generate;
+
+const MAX_DIR_SIZE: usize = 100_000;
+const MAX_SPACE_USE: usize = 40_000_000;
+
+#[non_exhaustive]
+#[derive(Debug, Snafu)]
+#[snafu(module(error), context(suffix(false)))]
+pub enum Error {
+    #[snafu(display("unable to parse the terminal output"))]
+    Parse { source: parser::Error },
+    #[snafu(display("the terminal output never lists the root directory"))]
+    MissingRoot,
+    #[snafu(display("no directory frees up {needed} bytes"))]
+    NoCandidate { needed: usize },
+}
+
+#[derive(Debug)]
+pub enum Cd<'s> {
+    Root,
+    Parent,
+    Dir(&'s str),
+}
+
+/// A line of the terminal output.
+#[derive(Debug)]
+pub enum Entry<'s> {
+    Cd(Cd<'s>),
+    Ls,
+    Dir(&'s str),
+    File(&'s str, usize),
+}
+
+pub fn parse_cd(input: &str) -> parser::Result<'_, Cd<'_>> {
+    let (input, _) = tag("$")(input)?;
+    let (input, _) = space1(input)?;
+    let (input, _) = tag("cd")(input)?;
+    let (input, _) = space1(input)?;
+
+    let (input, cd) = alt((
+        tag("/").map(|_| Cd::Root),
+        tag("..").map(|_| Cd::Parent),
+        alpha1.map(Cd::Dir),
+    ))(input)?;
+
+    Ok((input, cd))
+}
+
+pub fn parse_ls(input: &str) -> parser::Result<'_, ()> {
+    let (input, _) = tag("$")(input)?;
+    let (input, _) = space1(input)?;
+    let (input, _) = tag("ls")(input)?;
+    let (input, _) = space0(input)?;
+
+    Ok((input, ()))
+}
+
+pub fn parse_dir(input: &str) -> parser::Result<'_, &str> {
+    let (input, _) = tag("dir")(input)?;
+    let (input, _) = space1(input)?;
+    let (input, dir) = not_line_ending(input)?;
+
+    Ok((input, dir))
+}
+
+pub fn parse_file(input: &str) -> parser::Result<'_, (&str, usize)> {
+    let (input, size) = u64(input)?;
+    let (input, _) = space1(input)?;
+    let (input, name) = not_line_ending(input)?;
+
+    Ok((input, (name, size as usize)))
+}
+
+pub fn parse_line(input: &str) -> parser::Result<'_, Entry<'_>> {
+    let (input, entry) = alt((
+        parse_cd.map(Entry::Cd),
+        parse_ls.map(|_| Entry::Ls),
+        parse_dir.map(Entry::Dir),
+        parse_file.map(|(name, size)| Entry::File(name, size)),
+    ))(input)?;
+
+    let (input, _) = space0(input)?;
+
+    Ok((input, entry))
+}
+
+/// Parses every line of the terminal output.
+pub fn parse_input(input: &str) -> parser::Result<'_, Vec<Entry<'_>>> {
+    parser::lines(parse_line)(input)
+}
+
+/// Computes the total size of every directory, keyed by its absolute path.
+pub fn compute_dir_sizes(entries: Vec<Entry<'_>>) -> HashMap<PathBuf, usize> {
+    let mut path = PathBuf::new();
+    let mut dir_sizes: HashMap<_, usize> = HashMap::new();
+
+    for entry in entries {
+        match entry {
+            Entry::Cd(cd) => match cd {
+                Cd::Root => path.push("/"),
+                Cd::Parent => {
+                    path.pop();
+                }
+                Cd::Dir(dir) => path.push(dir),
+            },
+            Entry::File(_, size) => {
+                path.ancestors()
+                    .for_each(|dir| *dir_sizes.entry(dir.to_owned()).or_default() += size);
+            }
+            _ => {}
+        }
+    }
+
+    dir_sizes
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Error = Error;
+    type Parsed<'i> = HashMap<PathBuf, usize>;
+
+    const DAY: u8 = 7;
+    const INPUT: &'static str = include_str!("input/given.txt");
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+        let entries = parse_all(input, parse_input).context(error::Parse)?;
+
+        Ok(compute_dir_sizes(entries))
+    }
+
+    fn part_1(dir_sizes: &Self::Parsed<'_>) -> Result<Self::Answer1, Self::Error> {
+        Ok(dir_sizes
+            .values()
+            .copied()
+            .filter(|&size| size < MAX_DIR_SIZE)
+            .sum())
+    }
+
+    fn part_2(dir_sizes: &Self::Parsed<'_>) -> Result<Self::Answer2, Self::Error> {
+        let used = *dir_sizes.get(Path::new("/")).context(error::MissingRoot)?;
+        let needed = used.saturating_sub(MAX_SPACE_USE);
+
+        dir_sizes
+            .values()
+            .copied()
+            .filter(|&size| size > needed)
+            .min()
+            .context(error::NoCandidate { needed })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example_works() {
+        const INPUT: &str = include_str!("input/example.txt");
+
+        let dir_sizes = Day7::parse(INPUT).unwrap();
+
+        assert_eq!(dir_sizes[Path::new("/")], 48381165);
+        assert_eq!(Day7::part_1(&dir_sizes).unwrap(), 95437);
+        assert_eq!(Day7::part_2(&dir_sizes).unwrap(), 24933642);
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_common::answers;
+use day_7::Day7;
+
+#[test]
+fn recorded_answers() {
+    answers::verify::<Day7>(concat!(env!("CARGO_MANIFEST_DIR"), "/src/input"));
+}
@@ -0,0 +1,42 @@
+use std::path::Path;
+
+use aoc_common::solution::Solution;
+use day_7::{
+    compute_dir_sizes,
+    parse_input,
+    Cd,
+    Day7,
+    Entry,
+};
+
+const EXAMPLE: &str = include_str!("../src/input/example.txt");
+
+#[test]
+fn example_entries() {
+    let (rest, entries) = parse_input(EXAMPLE).unwrap();
+
+    assert_eq!(rest, "");
+    assert_eq!(entries.len(), 23);
+    assert!(matches!(entries[0], Entry::Cd(Cd::Root)));
+    assert!(matches!(entries[3], Entry::File("b.txt", 14848514)));
+}
+
+#[test]
+fn example_dir_sizes() {
+    let (_, entries) = parse_input(EXAMPLE).unwrap();
+
+    let sizes = compute_dir_sizes(entries);
+
+    assert_eq!(sizes[Path::new("/a/e")], 584);
+    assert_eq!(sizes[Path::new("/a")], 94853);
+    assert_eq!(sizes[Path::new("/d")], 24933642);
+    assert_eq!(sizes[Path::new("/")], 48381165);
+}
+
+#[test]
+fn example_solution() {
+    let sizes = Day7::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day7::part_1(&sizes).unwrap(), 95437);
+    assert_eq!(Day7::part_2(&sizes).unwrap(), 24933642);
+}
@@ -0,0 +1,31 @@
+use aoc_common::{
+    bench::{
+        bench_generated,
+        bench_solution,
+    },
+    solution::Solution,
+};
+use criterion::{
+    criterion_group,
+    criterion_main,
+    Criterion,
+};
+use day_8::Day8;
+
+/// Tiles the given map four times in each direction.
+fn synthetic() -> String {
+    let rows: Vec<_> = Day8::INPUT.lines().map(|line| line.repeat(4)).collect();
+    vec![rows.join("\n"); 4].join("\n")
+}
+
+fn solution(c: &mut Criterion) {
+    let synthetic = synthetic();
+    bench_solution::<Day8>(c, &[("given", Day8::INPUT), ("synthetic", &synthetic)]);
+}
+
+fn generated(c: &mut Criterion) {
+    bench_generated::<Day8>(c, &[10, 100, 1_000]);
+}
+
+criterion_group!(benches, solution, generated);
+criterion_main!(benches);
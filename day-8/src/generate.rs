@@ -0,0 +1,49 @@
+use aoc_common::generate::{
+    rand::Rng,
+    Generator,
+};
+
+use crate::Day8;
+
+impl Generator for Day8 {
+    /// Generates a square map of trees with `size` rows and columns, or three when fewer are asked
+    /// for, since the second part needs a tree inside the edges.
+    fn generate<R>(rng: &mut R, size: usize) -> String
+    where
+        R: Rng,
+    {
+        let size = size.max(3);
+        let mut input = String::with_capacity(size * (size + 1));
+        for _ in 0..size {
+            input.extend((0..size).map(|_| char::from(b'0' + rng.gen_range(0..=9))));
+            input.push('\n');
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{
+        generate::seeded,
+        solution::{
+            solve,
+            Solution,
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn generated_inputs_solve() {
+        for (seed, size) in [(0, 0), (1, 3), (2, 10), (3, 100)] {
+            let input = seeded::<Day8>(seed, size);
+
+            assert_eq!(
+                Day8::parse(&input).unwrap().dim(),
+                (size.max(3), size.max(3))
+            );
+            assert!(solve::<Day8>(&input, None).is_ok());
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use std::{
+    collections::HashSet,
+    convert::identity,
+    ops::ControlFlow,
+};
+
+use aoc_common::{
+    grid,
+    solution::Solution,
+};
+use ndarray::{
+    s,
+    Array2,
+    ArrayView1,
+    ArrayView2,
+};
+use snafu::prelude::*;
+
+pub mod generate;
+
+#[non_exhaustive]
+#[derive(Debug, Snafu)]
+#[snafu(module(error), context(suffix(false)))]
+pub enum Error {
+    #[snafu(display("unable to load the tree map"))]
+    Grid { source: grid::Error },
+    #[snafu(display("the tree map is {rows}x{cols} instead of square"))]
+    NotSquare { rows: usize, cols: usize },
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Error = Error;
+    type Parsed<'i> = Array2<u8>;
+
+    const DAY: u8 = 8;
+    const INPUT: &'static str = include_str!("input/given.txt");
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Self::Error> {
+        let map = grid::digit_grid(input).context(error::Grid)?;
+
+        let (rows, cols) = map.dim();
+        ensure!(rows == cols, error::NotSquare { rows, cols });
+        Ok(map)
+    }
+
+    fn part_1(map: &Self::Parsed<'_>) -> Result<Self::Answer1, Self::Error> {
+        Ok(count_visible_from_outside(map.view()).len())
+    }
+
+    fn part_2(map: &Self::Parsed<'_>) -> Result<Self::Answer2, Self::Error> {
+        let (_, score) = find_most_scenic_tree(map.view());
+        Ok(score)
+    }
+}
+
+/// Finds the interior tree with the highest scenic score, returning its position and score.
+///
+/// # Panics
+/// If the map is smaller than 3x3, as it then has no interior trees.
+pub fn find_most_scenic_tree(map: ArrayView2<u8>) -> ((usize, usize), usize) {
+    let side_len = map.nrows();
+    let scores = Array2::from_shape_fn((side_len - 2, side_len - 2), |(i, j)| {
+        let (i, j) = (i + 1, j + 1);
+        let height = map[(i, j)];
+        let above = count_scenic_line(height, map.slice(s![i, ..j; -1]));
+        let below = count_scenic_line(height, map.slice(s![i, j + 1..]));
+        let left = count_scenic_line(height, map.slice(s![..i; -1, j]));
+        let right = count_scenic_line(height, map.slice(s![i + 1.., j]));
+
+        above * below * left * right
+    });
+
+    scores
+        .indexed_iter()
+        .max_by_key(|(_, &v)| v)
+        .map(|((i, j), &v)| ((i + 1, j + 1), v))
+        .unwrap()
+}
+
+/// Counts the trees seen along the line of sight, stopping at the first one as tall as the tree.
+pub fn count_scenic_line(tree_height: u8, line_of_sight: ArrayView1<u8>) -> usize {
+    let score = line_of_sight.into_iter().try_fold(0, |count, &height| {
+        if height < tree_height {
+            ControlFlow::Continue(count + 1)
+        } else {
+            ControlFlow::Break(count + 1)
+        }
+    });
+
+    match score {
+        ControlFlow::Continue(score) | ControlFlow::Break(score) => score,
+    }
+}
+
+/// Finds the positions of every tree visible from outside of the square map.
+pub fn count_visible_from_outside(map: ArrayView2<u8>) -> HashSet<(usize, usize)> {
+    let side_len = map.nrows();
+    let mut visible = HashSet::with_capacity(map.len() / 8 + side_len * 4);
+
+    let iter = (0..side_len).flat_map(|i| [(i, 0), (0, i), (i, side_len - 1), (side_len - 1, i)]);
+    visible.extend(iter);
+
+    for i in 1..side_len - 1 {
+        insert_visible_from_outside(
+            &mut visible,
+            map.slice(s![i, ..side_len - 1]),
+            identity,
+            |index| (i, index),
+        );
+
+        insert_visible_from_outside(
+            &mut visible,
+            map.slice(s![i, 1..; -1]),
+            |index| side_len - 1 - index,
+            |index| (i, index),
+        );
+
+        insert_visible_from_outside(
+            &mut visible,
+            map.slice(s![..side_len - 1, i]),
+            identity,
+            |index| (index, i),
+        );
+
+        insert_visible_from_outside(
+            &mut visible,
+            map.slice(s![1..; -1, i]),
+            |index| side_len - 1 - index,
+            |index| (index, i),
+        );
+    }
+
+    visible
+}
+
+fn insert_visible_from_outside(
+    visible: &mut HashSet<(usize, usize)>,
+    line: ArrayView1<u8>,
+    adapt_index: impl Fn(usize) -> usize,
+    into_grid_index: impl Fn(usize) -> (usize, usize),
+) {
+    let mut iter = line
+        .iter()
+        .copied()
+        .enumerate()
+        .map(|(index, value)| (adapt_index(index), value));
+
+    let start = iter.next().unwrap();
+    let iter = iter.scan(start, scan_taller).flatten().map(into_grid_index);
+    visible.extend(iter);
+}
+
+fn scan_taller(prev: &mut (usize, u8), (index, height): (usize, u8)) -> Option<Option<usize>> {
+    let max = prev.1;
+    if height > max {
+        *prev = (index, height);
+        Some(Some(index))
+    } else {
+        Some(None)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example_works() {
+        const INPUT: &str = include_str!("input/example.txt");
+
+        let map = Day8::parse(INPUT).unwrap();
+
+        assert_eq!(Day8::part_1(&map).unwrap(), 21);
+        assert_eq!(find_most_scenic_tree(map.view()), ((3, 2), 8));
+    }
+}
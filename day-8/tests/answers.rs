@@ -0,0 +1,7 @@
+use aoc_common::answers;
+use day_8::Day8;
+
+#[test]
+fn recorded_answers() {
+    answers::verify::<Day8>(concat!(env!("CARGO_MANIFEST_DIR"), "/src/input"));
+}
@@ -0,0 +1,77 @@
+use std::collections::HashSet;
+
+use day_8::{
+    count_visible_from_outside,
+    find_most_scenic_tree,
+};
+use ndarray::Array2;
+use proptest::{
+    collection::vec,
+    prelude::*,
+};
+
+/// Trees seen from `(i, j)` when walking over the given positions, up to the first one as tall.
+fn naive_view(map: &Array2<u8>, (i, j): (usize, usize), line: &[(usize, usize)]) -> usize {
+    let height = map[(i, j)];
+    line.iter()
+        .position(|&pos| map[pos] >= height)
+        .map_or(line.len(), |index| index + 1)
+}
+
+/// Lines of sight from the tree towards each edge, nearest trees first.
+fn lines_of_sight(side_len: usize, (i, j): (usize, usize)) -> [Vec<(usize, usize)>; 4] {
+    [
+        (0..i).rev().map(|row| (row, j)).collect(),
+        (i + 1..side_len).map(|row| (row, j)).collect(),
+        (0..j).rev().map(|col| (i, col)).collect(),
+        (j + 1..side_len).map(|col| (i, col)).collect(),
+    ]
+}
+
+fn naive_visible(map: &Array2<u8>) -> HashSet<(usize, usize)> {
+    let side_len = map.nrows();
+    map.indexed_iter()
+        .map(|(pos, _)| pos)
+        .filter(|&pos| {
+            lines_of_sight(side_len, pos)
+                .iter()
+                .any(|line| line.iter().all(|&other| map[other] < map[pos]))
+        })
+        .collect()
+}
+
+fn naive_scenic_score(map: &Array2<u8>, pos: (usize, usize)) -> usize {
+    lines_of_sight(map.nrows(), pos)
+        .iter()
+        .map(|line| naive_view(map, pos, line))
+        .product()
+}
+
+/// Square maps of random heights, with room for at least one interior tree.
+fn maps() -> impl Strategy<Value = Array2<u8>> {
+    (3..16_usize).prop_flat_map(|side_len| {
+        vec(0..10_u8, side_len * side_len)
+            .prop_map(move |heights| Array2::from_shape_vec((side_len, side_len), heights).unwrap())
+    })
+}
+
+proptest! {
+    #[test]
+    fn visible_from_outside(map in maps()) {
+        prop_assert_eq!(count_visible_from_outside(map.view()), naive_visible(&map));
+    }
+
+    #[test]
+    fn most_scenic_tree(map in maps()) {
+        let side_len = map.nrows();
+        let best = (1..side_len - 1)
+            .flat_map(|i| (1..side_len - 1).map(move |j| (i, j)))
+            .map(|pos| naive_scenic_score(&map, pos))
+            .max();
+
+        let (pos, score) = find_most_scenic_tree(map.view());
+
+        prop_assert_eq!(Some(score), best);
+        prop_assert_eq!(naive_scenic_score(&map, pos), score);
+    }
+}
@@ -0,0 +1,35 @@
+use aoc_common::solution::Solution;
+use day_8::{
+    count_visible_from_outside,
+    find_most_scenic_tree,
+    Day8,
+};
+
+const EXAMPLE: &str = include_str!("../src/input/example.txt");
+
+#[test]
+fn example_trees() {
+    let map = Day8::parse(EXAMPLE).unwrap();
+
+    let visible = count_visible_from_outside(map.view());
+
+    assert_eq!(visible.len(), 21);
+    assert!(visible.contains(&(1, 1)));
+    assert!(!visible.contains(&(1, 3)));
+    assert_eq!(find_most_scenic_tree(map.view()), ((3, 2), 8));
+}
+
+#[test]
+fn example_solution() {
+    let map = Day8::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day8::part_1(&map).unwrap(), 21);
+    assert_eq!(Day8::part_2(&map).unwrap(), 8);
+}
+
+#[test]
+fn not_square() {
+    let result = Day8::parse("123\n456\n");
+
+    assert!(result.is_err());
+}